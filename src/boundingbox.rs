@@ -1,6 +1,19 @@
-use geolocation::GeoLocation;
+use geolocation::{GeoLocation, EARTH_RADIUS};
+use geohash::decode;
+use cover::CellGrid;
 
 /// A bounding box composed by 2 geolocations
+///
+/// Note the `Default` box, same as `BoundingBox::new()`, is a single point at
+/// latitude and longitude 0, not the whole world. Use `BoundingBox::world()` to
+/// start from the whole world.
+///
+/// A box with `min_lon` greater than `max_lon` wraps around the antimeridian, going
+/// east from `min_lon` to 180 and on from -180 to `max_lon`. Such boxes are made by
+/// `BoundingBox::from_coordinates_wrapping`, and methods treat them as the two halves
+/// on either side of the antimeridian, e.g. covering a wrapped box gives the cells of
+/// both halves. Constructors taking points or GeoHashes still sort the longitudes,
+/// they never make a wrapped box.
 #[derive(Default, Clone, Copy, PartialEq)]
 pub struct BoundingBox {
 	pub min_lat : f64,
@@ -30,6 +43,24 @@ impl BoundingBox {
         }
     }
 
+    /// Create a new `BoundingBox` covering the whole world
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::world();
+    /// assert!(b==geohashrust::BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0));
+    /// assert!(b.contains(&geohashrust::GeoLocation::from_coordinates(-90.0, 180.0)));
+    /// ```
+    pub fn world() -> BoundingBox {
+        BoundingBox {
+            min_lat: -90.0,
+            max_lat: 90.0,
+            min_lon: -180.0,
+            max_lon: 180.0,
+        }
+    }
+
     /// Create a new `BoudingBox` with 4 coordinates
     ///
     /// # Example
@@ -50,6 +81,32 @@ impl BoundingBox {
         }
     }
 
+    /// Create a new `BoudingBox` with 4 coordinates, wrapping around the antimeridian
+    /// if `minlon` is greater than `maxlon`
+    ///
+    /// Unlike `from_coordinates` the longitudes are kept in their order, so a box from
+    /// 170 to -170 is 20 degrees wide across the antimeridian instead of 340 degrees
+    /// wide across the prime meridian. Latitudes are still sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::{BoundingBox, GeoLocation};
+    /// let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    /// assert!(b.wraps_antimeridian());
+    /// assert_eq!(b.longitude_range(), 20.0);
+    /// assert!(b.contains(&GeoLocation::from_coordinates(-17.7, 178.0)));
+    /// assert!(!b.contains(&GeoLocation::from_coordinates(-17.7, 0.0)));
+    /// ```
+    pub fn from_coordinates_wrapping(minlat:f64, maxlat:f64, minlon:f64, maxlon:f64) -> BoundingBox {
+        BoundingBox {
+            min_lat: minlat.min(maxlat),
+            max_lat: minlat.max(maxlat),
+            min_lon: minlon,
+            max_lon: maxlon,
+        }
+    }
+
     /// Creates a new `BoundingBox` with 2 GeoLocations
     ///
     /// # Example
@@ -79,6 +136,41 @@ impl BoundingBox {
         }
     }
 
+    /// Create a new `BoundingBox` around a circle, with the radius in meters
+    ///
+    /// The box spans all longitudes when the circle reaches a pole, and is clipped at
+    /// the antimeridian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let center=geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let b=geohashrust::BoundingBox::from_center_radius(&center, 1000.0);
+    /// assert!((b.max_lat-0.008993).abs() < 1e-6);
+    /// assert!((b.max_lon+b.min_lon).abs() < 1e-12);
+    /// ```
+    pub fn from_center_radius(center: &GeoLocation, radius_m: f64) -> BoundingBox {
+        let angle = radius_m / (EARTH_RADIUS * 1000.0);
+        let min_lat = center.latitude - angle.to_degrees();
+        let max_lat = center.latitude + angle.to_degrees();
+        if min_lat <= -90.0 || max_lat >= 90.0 {
+            return BoundingBox {
+                min_lat: min_lat.max(-90.0),
+                max_lat: max_lat.min(90.0),
+                min_lon: -180.0,
+                max_lon: 180.0,
+            };
+        }
+        // Widest longitude offset reached by the circle
+        let dlon = (angle.sin() / center.latitude.to_radians().cos()).min(1.0).asin().to_degrees();
+        BoundingBox {
+            min_lat,
+            max_lat,
+            min_lon: (center.longitude - dlon).max(-180.0),
+            max_lon: (center.longitude + dlon).min(180.0),
+        }
+    }
+
     /// Creates a new `BoundingBox` with the merge of 2 BoundingBoxes
     ///
     /// # Example
@@ -111,12 +203,80 @@ impl BoundingBox {
     /// assert_eq!(box3.max_lon, 145.0);
     /// ```
     pub fn merged(one: &BoundingBox, other: &BoundingBox) -> BoundingBox {
-    	BoundingBox {
-    		min_lat: one.min_lat.min(other.min_lat),
-    		max_lat: one.max_lat.max(other.max_lat),
-    		min_lon: one.min_lon.min(other.min_lon),
-    		max_lon: one.max_lon.max(other.max_lon),
-    	}
+    	let mut output = *one;
+    	output.merge_with(other);
+    	output
+    }
+
+    /// Creates a new `BoundingBox` with the merge of any number of BoundingBoxes,
+    /// or `None` if there are none
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let boxes=[geohashrust::decode("wtw3s"), geohashrust::decode("wtw37")];
+    /// let b=geohashrust::BoundingBox::union(&boxes).unwrap();
+    /// assert!(b==geohashrust::BoundingBox::merged(&boxes[0], &boxes[1]));
+    /// assert!(geohashrust::BoundingBox::union(&[]).is_none());
+    /// ```
+    pub fn union<'a, I: IntoIterator<Item = &'a BoundingBox>>(boxes: I) -> Option<BoundingBox> {
+        let mut iter = boxes.into_iter();
+        let mut output = *iter.next()?;
+        for b in iter {
+            output.merge_with(b);
+        }
+        Some(output)
+    }
+
+    /// Creates the smallest `BoundingBox` containing all `points`, or `None` if there
+    /// are none
+    ///
+    /// Longitudes are compared as they are, so points on both sides of the antimeridian
+    /// give a box spanning the other way around the world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::{BoundingBox, GeoLocation};
+    /// let points=[GeoLocation::from_coordinates(10.0, 40.0), GeoLocation::from_coordinates(20.0, 30.0)];
+    /// let b=BoundingBox::from_points(&points).unwrap();
+    /// assert!(b==BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0));
+    /// assert!(BoundingBox::from_points(&[]).is_none());
+    /// ```
+    pub fn from_points(points: &[GeoLocation]) -> Option<BoundingBox> {
+        let mut iter = points.iter();
+        let first = iter.next()?;
+        let mut output = BoundingBox::from_geolocations(first, first);
+        for p in iter {
+            output.expand_to_include(p);
+        }
+        Some(output)
+    }
+
+    /// Creates a new `BoundingBox` with the merge of the cells of GeoHashes, or `None`
+    /// if there are none
+    ///
+    /// Longitudes are merged as they are, so cells on both sides of the antimeridian
+    /// give a box spanning the other way around the world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a GeoHash contains a character outside of the base32 alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let hashes=vec!["wtw3s".to_string(), "wtw37".to_string()];
+    /// let b=geohashrust::BoundingBox::from_geohashes(&hashes).unwrap();
+    /// assert!(b==geohashrust::BoundingBox::merged(&geohashrust::decode("wtw3s"), &geohashrust::decode("wtw37")));
+    /// ```
+    pub fn from_geohashes(hashes: &[String]) -> Option<BoundingBox> {
+        let mut iter = hashes.iter();
+        let mut output = decode(iter.next()?);
+        for h in iter {
+            output.merge_with(&decode(h));
+        }
+        Some(output)
     }
 
     /// Get the center point of the bounding box
@@ -126,21 +286,25 @@ impl BoundingBox {
     /// ```
     /// let b=geohashrust::BoundingBox::from_geolocations(
     ///     &geohashrust::GeoLocation{
-    ///         latitude: 23.0,
-    ///         longitude: 89.0,
+    ///         latitude: 20.0,
+    ///         longitude: 100.0,
     ///     },
     ///     &geohashrust::GeoLocation{
-    ///         latitude: 67.0,
-    ///         longitude: 45.0,
+    ///         latitude: 30.0,
+    ///         longitude: 140.0,
     ///     },
     /// );
-    /// assert_eq!(b.center().latitude, 45.0);
-    /// assert_eq!(b.center().longitude, 67.0);
+    /// assert_eq!(b.center().latitude, 25.0);
+    /// assert_eq!(b.center().longitude, 120.0);
     /// ```
     pub fn center(&self) -> GeoLocation {
+        let mut longitude = (self.min_lon+self.max_lon)/2.0;
+        if self.wraps_antimeridian() {
+            longitude += if longitude > 0.0 { -180.0 } else { 180.0 };
+        }
         GeoLocation {
             latitude: (self.min_lat+self.max_lat)/2.0,
-            longitude: (self.min_lon+self.max_lon)/2.0,
+            longitude,
         }
     }
 
@@ -244,6 +408,48 @@ impl BoundingBox {
         }
     }
 
+    /// Get the 4 corners of the bounding box, in the order top-left, top-right,
+    /// bottom-left and bottom-right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(23.0, 67.0, 45.0, 89.0);
+    /// let corners=b.corners();
+    /// assert!(corners[0]==b.top_left());
+    /// assert!(corners[3]==b.bottom_right());
+    /// ```
+    pub fn corners(&self) -> [GeoLocation; 4] {
+        [self.top_left(), self.top_right(), self.bottom_left(), self.bottom_right()]
+    }
+
+    /// Get the bounding box as a Well-Known Text polygon
+    ///
+    /// Coordinates are in longitude-latitude order, going counterclockwise from the
+    /// bottom-left corner, and the ring is closed by repeating it at the end. A box
+    /// wrapping around the antimeridian is split there into a multipolygon, the
+    /// eastern half first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(23.0, 67.5, 45.0, 89.0);
+    /// assert_eq!(b.to_wkt(), "POLYGON((45 23, 89 23, 89 67.5, 45 67.5, 45 23))");
+    /// let b=geohashrust::BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    /// assert_eq!(b.to_wkt(), "MULTIPOLYGON(((170 -20, 180 -20, 180 -10, 170 -10, 170 -20)), \
+    ///                         ((-180 -20, -170 -20, -170 -10, -180 -10, -180 -20)))");
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        let ring = |b: &BoundingBox| format!("(({0} {1}, {2} {1}, {2} {3}, {0} {3}, {0} {1}))",
+            b.min_lon, b.min_lat, b.max_lon, b.max_lat);
+        let rings: Vec<String> = self.halves().map(|b| ring(&b)).collect();
+        if rings.len() == 1 {
+            format!("POLYGON{}", rings[0])
+        } else {
+            format!("MULTIPOLYGON({})", rings.join(", "))
+        }
+    }
+
     /// Get the latitude range of the bounding box
     ///
     /// # Example
@@ -283,7 +489,36 @@ impl BoundingBox {
     /// assert_eq!(b.longitude_range(), 54.0);
     /// ```
     pub fn longitude_range(&self) -> f64 {
-    	self.max_lon - self.min_lon
+    	if self.wraps_antimeridian() {
+    		self.max_lon - self.min_lon + 360.0
+    	} else {
+    		self.max_lon - self.min_lon
+    	}
+    }
+
+    /// Test if the bounding box wraps around the antimeridian, i.e. `min_lon` is
+    /// greater than `max_lon`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::BoundingBox;
+    /// assert!(BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0).wraps_antimeridian());
+    /// assert!(!BoundingBox::from_coordinates(-20.0, -10.0, 170.0, -170.0).wraps_antimeridian());
+    /// ```
+    pub fn wraps_antimeridian(&self) -> bool {
+        self.min_lon > self.max_lon
+    }
+
+    // Split a box wrapping around the antimeridian into its eastern and western halves,
+    // other boxes are left whole
+    fn halves(&self) -> impl Iterator<Item = BoundingBox> {
+        let (east, west) = if self.wraps_antimeridian() {
+            (BoundingBox { max_lon: 180.0, ..*self }, Some(BoundingBox { min_lon: -180.0, ..*self }))
+        } else {
+            (*self, None)
+        };
+        Some(east).into_iter().chain(west)
     }
 
     /// Get the latitude error from the center point of the bounding box
@@ -328,6 +563,116 @@ impl BoundingBox {
         self.longitude_range()/2.0
     }
 
+    /// Get the area of the bounding box on the Earth's surface in square kilometers, same
+    /// as `area_km2`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
+    /// let r=6371.009;
+    /// assert!((b.area()-4.0*std::f64::consts::PI*r*r).abs() < 1.0);
+    /// assert_eq!(geohashrust::BoundingBox::new().area(), 0.0);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.area_km2()
+    }
+
+    /// Get the area of the bounding box on the Earth's surface in square kilometers
+    ///
+    /// The box is treated as a lat/lon rectangle on a sphere of radius `EARTH_RADIUS`, whose
+    /// area is `R² * (lon2-lon1) * (sin(lat2)-sin(lat1))`. Degenerate boxes have no area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(-10.0, 10.0, 0.0, 10.0);
+    /// assert!((b.area_km2()-2_460_334.0).abs() < 1.0);
+    /// assert_eq!(geohashrust::BoundingBox::from_coordinates(10.0, 10.0, 0.0, 10.0).area_km2(), 0.0);
+    /// ```
+    pub fn area_km2(&self) -> f64 {
+        EARTH_RADIUS * EARTH_RADIUS
+            * self.longitude_range().to_radians()
+            * (self.max_lat.to_radians().sin() - self.min_lat.to_radians().sin())
+    }
+
+    /// Get the length in kilometers of the diagonal of the bounding box, from the top-left
+    /// to the bottom-right corner
+    ///
+    /// The corners are joined along the great circle, so a box wrapping around the
+    /// antimeridian measures the same as the box of the same size anywhere else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(0.0, 1.0, 0.0, 1.0);
+    /// assert!((b.diagonal_km()-157.25).abs() < 0.01);
+    /// ```
+    pub fn diagonal_km(&self) -> f64 {
+        self.top_left().distance_to(&self.bottom_right())
+    }
+
+    /// Get the distance in kilometers from a `GeoLocation` to the closest point of the
+    /// bounding box, which is 0 if the box contains it
+    ///
+    /// The closest point is found by clamping the latitude and longitude into the box,
+    /// or into each half of a box wrapping around the antimeridian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::{BoundingBox, GeoLocation};
+    /// let b=BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    /// assert_eq!(b.distance_to_point(&GeoLocation::from_coordinates(15.0, 35.0)), 0.0);
+    /// let d=b.distance_to_point(&GeoLocation::from_coordinates(21.0, 35.0));
+    /// assert!((d-GeoLocation::from_coordinates(20.0, 35.0).distance_to(&GeoLocation::from_coordinates(21.0, 35.0))).abs() < 1e-9);
+    /// ```
+    pub fn distance_to_point(&self, point: &GeoLocation) -> f64 {
+        self.halves().map(|b| {
+            let closest = GeoLocation {
+                latitude: point.latitude.clamp(b.min_lat, b.max_lat),
+                longitude: point.longitude.clamp(b.min_lon, b.max_lon),
+            };
+            closest.distance_to(point)
+        }).fold(f64::INFINITY, f64::min)
+    }
+
+    /// Get the position of a `GeoLocation` within the bounding box as `(u, v)`
+    ///
+    /// `u` goes along the longitude and `v` along the latitude, both are clamped into
+    /// `[0, 1]` with `(0, 0)` at the bottom-left and `(1, 1)` at the top-right corner.
+    /// A degenerate axis always maps to 0.5. For a box wrapping around the antimeridian
+    /// `u` goes east across it, and a point outside of the box is clamped to the closer
+    /// of the western and eastern edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    /// assert_eq!(b.relative_position(&b.center()), (0.5, 0.5));
+    /// assert_eq!(b.relative_position(&b.top_right()), (1.0, 1.0));
+    /// assert_eq!(b.relative_position(&geohashrust::GeoLocation::from_coordinates(12.5, 50.0)), (1.0, 0.25));
+    /// ```
+    pub fn relative_position(&self, point: &GeoLocation) -> (f64, f64) {
+        let position = |value: f64, min: f64, range: f64| {
+            if range > 0.0 {
+                ((value - min) / range).clamp(0.0, 1.0)
+            } else {
+                0.5
+            }
+        };
+        let mut longitude = point.longitude;
+        if self.wraps_antimeridian() && longitude < self.min_lon {
+            longitude += 360.0;
+            // Outside of the box, closer to its western edge going on eastwards
+            if longitude - self.min_lon - self.longitude_range() > self.min_lon + 360.0 - longitude {
+                longitude = self.min_lon;
+            }
+        }
+        (position(longitude, self.min_lon, self.longitude_range()),
+         position(point.latitude, self.min_lat, self.latitude_range()))
+    }
+
     /// Test if a `GeoLocation` is in the bounding box
     ///
     /// # Example
@@ -347,12 +692,97 @@ impl BoundingBox {
     /// assert!(!b.contains(&geohashrust::GeoLocation::from_coordinates(13.0, 55.0)));
     /// ```
     pub fn contains(&self, point: &GeoLocation) -> bool {
-    	(point.latitude >= self.min_lat) && (point.latitude <= self.max_lat)
-    	&& (point.longitude >= self.min_lon) && (point.longitude <= self.max_lon)
+    	let in_lon = if self.wraps_antimeridian() {
+    		(point.longitude >= self.min_lon) || (point.longitude <= self.max_lon)
+    	} else {
+    		(point.longitude >= self.min_lon) && (point.longitude <= self.max_lon)
+    	};
+    	(point.latitude >= self.min_lat) && (point.latitude <= self.max_lat) && in_lon
+    }
+
+    /// Test if another `BoundingBox` is entirely in the bounding box, borders included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::decode("wtw3");
+    /// assert!(b.contains_box(&geohashrust::decode("wtw3s")));
+    /// assert!(b.contains_box(&b));
+    /// assert!(!geohashrust::decode("wtw3s").contains_box(&b));
+    /// ```
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        other.halves().all(|o| self.halves().any(|b| {
+            b.min_lat <= o.min_lat && o.max_lat <= b.max_lat
+                && b.min_lon <= o.min_lon && o.max_lon <= b.max_lon
+        }))
+    }
+
+    /// Test if 2 bounding boxes overlap, boxes sharing only a border count too
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::decode("wtw3s");
+    /// assert!(b.intersects(&geohashrust::decode("wtw3")));
+    /// assert!(b.intersects(&geohashrust::decode("wtw37")));
+    /// assert!(!b.intersects(&geohashrust::decode("wtw3r")));
+    /// ```
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.halves().any(|b| other.halves().any(|o| {
+            b.min_lat <= o.max_lat && o.min_lat <= b.max_lat
+                && b.min_lon <= o.max_lon && o.min_lon <= b.max_lon
+        }))
+    }
+
+    /// Get the overlap of 2 bounding boxes, or `None` if they don't intersect
+    ///
+    /// Boxes sharing only a border intersect in a degenerate box along that border.
+    ///
+    /// A box wrapping around the antimeridian and a box reaching it from both sides, e.g.
+    /// from 170 to -170 and from -175 to 175, overlap in 2 separate pieces that no single
+    /// box can hold. The result is `None` then too, even though `intersects` is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::BoundingBox;
+    /// let a=BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    /// let i=a.intersection(&BoundingBox::from_coordinates(15.0, 25.0, 35.0, 45.0)).unwrap();
+    /// assert!(i==BoundingBox::from_coordinates(15.0, 20.0, 35.0, 40.0));
+    /// assert!(a.intersection(&BoundingBox::from_coordinates(21.0, 25.0, 35.0, 45.0)).is_none());
+    /// ```
+    pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        let mut pieces = Vec::with_capacity(2);
+        for b in self.halves() {
+            for o in other.halves() {
+                if b.intersects(&o) {
+                    pieces.push(BoundingBox {
+                        min_lat: b.min_lat.max(o.min_lat),
+                        max_lat: b.max_lat.min(o.max_lat),
+                        min_lon: b.min_lon.max(o.min_lon),
+                        max_lon: b.max_lon.min(o.max_lon),
+                    });
+                }
+            }
+        }
+        match pieces.len() {
+            0 => None,
+            1 => Some(pieces[0]),
+            // Pieces meeting at the antimeridian make up a wrapped box
+            2 if pieces[0].max_lon == 180.0 && pieces[1].min_lon == -180.0 => Some(BoundingBox {
+                max_lon: pieces[1].max_lon,
+                ..pieces[0]
+            }),
+            _ => None,
+        }
     }
 
     /// Merge another `BoundingBox` into this one
     ///
+    /// Longitudes of boxes not wrapping around the antimeridian are merged as they are.
+    /// If either box wraps, the result is the narrowest box going around the world
+    /// either way that holds both, which may wrap too.
+    ///
     /// # Example
     ///
     /// ```
@@ -383,6 +813,14 @@ impl BoundingBox {
     /// assert_eq!(box1.max_lon, 145.0);
     /// ```
     pub fn merge_with(&mut self, other: &BoundingBox) {
+    	if self.wraps_antimeridian() || other.wraps_antimeridian() {
+    		let (min_lon, max_lon) = merge_longitudes(self, other);
+    		self.min_lat = self.min_lat.min(other.min_lat);
+    		self.max_lat = self.max_lat.max(other.max_lat);
+    		self.min_lon = min_lon;
+    		self.max_lon = max_lon;
+    		return;
+    	}
     	if other.min_lat < self.min_lat {
     		self.min_lat=other.min_lat
     	}
@@ -396,4 +834,192 @@ impl BoundingBox {
     		self.max_lon=other.max_lon
     	}
     }
+
+    /// Grow the bounding box as little as possible so it contains a `GeoLocation`
+    ///
+    /// A box wrapping around the antimeridian grows on the side closer to the point, so
+    /// it keeps wrapping. Other boxes grow along the longitudes as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut b=geohashrust::BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    /// b.expand_to_include(&geohashrust::GeoLocation::from_coordinates(25.0, 35.0));
+    /// assert!(b==geohashrust::BoundingBox::from_coordinates(10.0, 25.0, 30.0, 40.0));
+    /// ```
+    pub fn expand_to_include(&mut self, point: &GeoLocation) {
+        self.min_lat = self.min_lat.min(point.latitude);
+        self.max_lat = self.max_lat.max(point.latitude);
+        if self.wraps_antimeridian() {
+            let point_box = BoundingBox::from_geolocations(point, point);
+            let (min_lon, max_lon) = merge_longitudes(self, &point_box);
+            self.min_lon = min_lon;
+            self.max_lon = max_lon;
+        } else {
+            self.min_lon = self.min_lon.min(point.longitude);
+            self.max_lon = self.max_lon.max(point.longitude);
+        }
+    }
+
+    /// Creates a new `BoundingBox` with each edge of this one moved outward by `meters`
+    ///
+    /// Latitudes grow by `meters` along a meridian and are clamped to ±90. Longitudes grow
+    /// by the same distance measured along the parallel at the center of the box, i.e.
+    /// scaled by the cosine of the center latitude, and are clamped to ±180. Parallels
+    /// get shorter towards the poles, so the longitude growth is only approximate for
+    /// tall boxes, and once the expanded box reaches a pole it spans all longitudes. A
+    /// box wrapping around the antimeridian grows on both sides of it until it spans
+    /// all longitudes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0).expand_by_meters(1000.0);
+    /// assert!((b.max_lat-20.008993).abs() < 1e-6);
+    /// assert!((b.max_lon-40.009310).abs() < 1e-6);
+    /// ```
+    pub fn expand_by_meters(&self, meters: f64) -> BoundingBox {
+        let dlat = (meters / (EARTH_RADIUS * 1000.0)).to_degrees();
+        let min_lat = (self.min_lat - dlat).max(-90.0);
+        let max_lat = (self.max_lat + dlat).min(90.0);
+        if min_lat <= -90.0 || max_lat >= 90.0 {
+            return BoundingBox {
+                min_lat,
+                max_lat,
+                min_lon: -180.0,
+                max_lon: 180.0,
+            };
+        }
+        let dlon = dlat / self.center().latitude.to_radians().cos();
+        if self.wraps_antimeridian() {
+            if self.longitude_range() + 2.0 * dlon >= 360.0 {
+                return BoundingBox {
+                    min_lat,
+                    max_lat,
+                    min_lon: -180.0,
+                    max_lon: 180.0,
+                };
+            }
+            return BoundingBox {
+                min_lat,
+                max_lat,
+                min_lon: self.min_lon - dlon,
+                max_lon: self.max_lon + dlon,
+            };
+        }
+        BoundingBox {
+            min_lat,
+            max_lat,
+            min_lon: (self.min_lon - dlon).max(-180.0),
+            max_lon: (self.max_lon + dlon).min(180.0),
+        }
+    }
+
+    /// Split the bounding box into a grid of `rows` by `cols` equal sub-boxes
+    ///
+    /// Sub-boxes are in row-major order starting from the bottom-left corner, i.e. going
+    /// east along the southernmost row first. Neighbouring sub-boxes share their edges
+    /// exactly and the outer edges are the ones of the box. The result is empty if
+    /// `rows` or `cols` is 0. Columns of a box wrapping around the antimeridian go east
+    /// across it, the column straddling it wraps too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::BoundingBox;
+    /// let parts=BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0).subdivide(2, 2);
+    /// assert_eq!(parts.len(), 4);
+    /// assert!(parts[0]==BoundingBox::from_coordinates(10.0, 15.0, 30.0, 35.0));
+    /// assert!(parts[3]==BoundingBox::from_coordinates(15.0, 20.0, 35.0, 40.0));
+    /// ```
+    pub fn subdivide(&self, rows: usize, cols: usize) -> Vec<BoundingBox> {
+        let edge = |min: f64, max: f64, i: usize, n: usize| {
+            if i == n { max } else { min + (max - min) * i as f64 / n as f64 }
+        };
+        // Longitudes go around through the antimeridian for a wrapped box, where the
+        // column east of it starts from -180
+        let lon_edge = |i: usize, west: bool| {
+            if i == cols {
+                return self.max_lon;
+            }
+            let lon = self.min_lon + self.longitude_range() * i as f64 / cols as f64;
+            if lon > 180.0 || (west && lon == 180.0 && self.wraps_antimeridian()) { lon - 360.0 } else { lon }
+        };
+        let mut output = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                output.push(BoundingBox {
+                    min_lat: edge(self.min_lat, self.max_lat, row, rows),
+                    max_lat: edge(self.min_lat, self.max_lat, row + 1, rows),
+                    min_lon: lon_edge(col, true),
+                    max_lon: lon_edge(col + 1, false),
+                });
+            }
+        }
+        output
+    }
+
+    /// Get the GeoHashes with given precision along the border of the box
+    ///
+    /// These are the outermost rows and columns of `cover_bounding_box`, in the same
+    /// order, without the interior cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let b=geohashrust::decode("wtw3");
+    /// assert_eq!(b.border_cells(4), vec!["wtw3"]);
+    /// let cells=b.border_cells(5);
+    /// assert_eq!(cells.len(), 20);
+    /// assert!(!cells.contains(&"wtw3s".to_string()));
+    /// ```
+    pub fn border_cells(&self, precision: u8) -> Vec<String> {
+        let grid = CellGrid::new(self, precision);
+        grid.hashes(|row, col| {
+            row == grid.first_row || row == grid.last_row || col == grid.first_col || col == grid.last_col
+        })
+    }
+
+    /// Expand the bounding box outwards to the borders of the GeoHash cells with given
+    /// precision covering it
+    ///
+    /// The result is the bounding box of the cells from `cover_bounding_box`, so it's
+    /// tiled exactly by them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let cell=geohashrust::decode("wtw3s");
+    /// let b=geohashrust::BoundingBox::from_coordinates(
+    ///     cell.center().latitude, cell.max_lat, cell.center().longitude, cell.max_lon);
+    /// assert!(b.align_to_geohash_grid(5)==cell);
+    /// ```
+    pub fn align_to_geohash_grid(&self, precision: u8) -> BoundingBox {
+        let grid = CellGrid::new(self, precision);
+        let first = grid.cell(grid.first_row, grid.first_col);
+        let last = grid.cell(grid.last_row, grid.last_col);
+        let (min_lon, max_lon) = if grid.all_columns() { (-180.0, 180.0) } else { (first.min_lon, last.max_lon) };
+        BoundingBox {
+            min_lat: first.min_lat,
+            max_lat: last.max_lat,
+            min_lon,
+            max_lon,
+        }
+    }
+}
+
+// Get the longitudes of the narrowest box going either way around the world that
+// holds the longitudes of both boxes
+fn merge_longitudes(one: &BoundingBox, other: &BoundingBox) -> (f64, f64) {
+    // Width of the box going east from the start of `a` to the end of `b`
+    let width = |a: &BoundingBox, b: &BoundingBox| {
+        a.longitude_range().max((b.min_lon - a.min_lon).rem_euclid(360.0) + b.longitude_range())
+    };
+    let (from_one, from_other) = (width(one, other), width(other, one));
+    let (start, width) = if from_one <= from_other { (one.min_lon, from_one) } else { (other.min_lon, from_other) };
+    if width >= 360.0 {
+        return (-180.0, 180.0);
+    }
+    let end = start + width;
+    (start, if end > 180.0 { end - 360.0 } else { end })
 }
@@ -0,0 +1,505 @@
+use std::collections::HashSet;
+use std::f64::consts::PI;
+
+use geolocation::{GeoLocation, EARTH_RADIUS};
+use boundingbox::BoundingBox;
+use error::GeohashError;
+use geohash::{encode, decode, cell_size, char_index, BASE32_CODES};
+
+// Get the range of cell indices on one axis overlapping `[min, max]`, where the axis
+// starts at `origin` and is divided into `count` cells of `size`
+fn cell_range(min: f64, max: f64, origin: f64, size: f64, count: i64) -> (i64, i64) {
+    let first = ((min - origin) / size).floor() as i64;
+    // A box ending exactly on a cell border doesn't spill into the next cell
+    let last = (((max - origin) / size).ceil() as i64 - 1).max(first);
+    (first.clamp(0, count - 1), last.clamp(0, count - 1))
+}
+
+// The block of cells with given precision overlapping a bounding box
+//
+// For a box wrapping around the antimeridian `last_col` goes past the last column
+// of the world, columns are taken modulo `cols` to get the cells.
+pub(crate) struct CellGrid {
+    precision: u8,
+    lat_size: f64,
+    lon_size: f64,
+    cols: i64,
+    pub(crate) first_row: i64,
+    pub(crate) last_row: i64,
+    pub(crate) first_col: i64,
+    pub(crate) last_col: i64,
+}
+
+impl CellGrid {
+    pub(crate) fn new(bbox: &BoundingBox, precision: u8) -> CellGrid {
+        let (lat_size, lon_size) = cell_size(precision);
+        let rows = (180.0 / lat_size).round() as i64;
+        let cols = (360.0 / lon_size).round() as i64;
+        let (first_row, last_row) = cell_range(bbox.min_lat, bbox.max_lat, -90.0, lat_size, rows);
+        let (first_col, last_col) = if bbox.wraps_antimeridian() {
+            let (first_col, _) = cell_range(bbox.min_lon, 180.0, -180.0, lon_size, cols);
+            let (_, last_col) = cell_range(-180.0, bbox.max_lon, -180.0, lon_size, cols);
+            (first_col, (last_col + cols).min(first_col + cols - 1))
+        } else {
+            cell_range(bbox.min_lon, bbox.max_lon, -180.0, lon_size, cols)
+        };
+        CellGrid {
+            precision,
+            lat_size,
+            lon_size,
+            cols,
+            first_row,
+            last_row,
+            first_col,
+            last_col,
+        }
+    }
+
+    // Get the GeoHash of a cell by its row and column in the world grid
+    pub(crate) fn hash(&self, row: i64, col: i64) -> String {
+        let center = GeoLocation {
+            latitude: -90.0 + (row as f64 + 0.5) * self.lat_size,
+            longitude: -180.0 + (col.rem_euclid(self.cols) as f64 + 0.5) * self.lon_size,
+        };
+        encode(&center, self.precision)
+    }
+
+    // Get the bounding box of a cell by its row and column in the world grid
+    pub(crate) fn cell(&self, row: i64, col: i64) -> BoundingBox {
+        let col = col.rem_euclid(self.cols);
+        BoundingBox {
+            min_lat: -90.0 + row as f64 * self.lat_size,
+            max_lat: -90.0 + (row + 1) as f64 * self.lat_size,
+            min_lon: -180.0 + col as f64 * self.lon_size,
+            max_lon: -180.0 + (col + 1) as f64 * self.lon_size,
+        }
+    }
+
+    // Test if the cell at a row and column of the world grid is in the block
+    pub(crate) fn contains(&self, row: i64, col: i64) -> bool {
+        (self.first_row..=self.last_row).contains(&row)
+            && (col - self.first_col).rem_euclid(self.cols) <= self.last_col - self.first_col
+    }
+
+    // Test if the block spans all columns of the world grid
+    pub(crate) fn all_columns(&self) -> bool {
+        self.last_col - self.first_col + 1 >= self.cols
+    }
+
+    // Get the GeoHashes of the cells passing a filter on their row and column, row by row
+    pub(crate) fn hashes<F: Fn(i64, i64) -> bool>(&self, filter: F) -> Vec<String> {
+        let mut output = Vec::new();
+        for row in self.first_row..=self.last_row {
+            for col in self.first_col..=self.last_col {
+                if filter(row, col) {
+                    output.push(self.hash(row, col));
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Get all GeoHashes with given precision whose cells overlap a `BoundingBox`
+///
+/// The cells are computed from the grid rows and columns covering the corners of the
+/// box, so there are no duplicates, and they're ordered row by row from the bottom-left
+/// corner. A box smaller than a cell is covered by at least one GeoHash, while cells
+/// only sharing a border with the box are left out. The number of cells grows quickly
+/// with the precision, so keep the box small for long hashes. Rows of a box wrapping
+/// around the antimeridian go east across it.
+///
+/// # Example
+///
+/// ```
+/// let b=geohashrust::decode("wtw3s");
+/// assert_eq!(geohashrust::cover_bounding_box(&b, 5), vec!["wtw3s"]);
+/// assert_eq!(geohashrust::cover_bounding_box(&b, 6).len(), 32);
+/// let c=b.center();
+/// let b=geohashrust::BoundingBox::from_coordinates(c.latitude, c.latitude+0.001, c.longitude, c.longitude+0.001);
+/// assert_eq!(geohashrust::cover_bounding_box(&b, 5), vec!["wtw3s"]);
+/// assert_eq!(geohashrust::cover_bounding_box(&geohashrust::decode("wtw3"), 5).len(), 32);
+/// ```
+pub fn cover_bounding_box(bbox: &BoundingBox, precision: u8) -> Vec<String> {
+    CellGrid::new(bbox, precision).hashes(|_, _| true)
+}
+
+/// Get all GeoHashes with given precision whose cells intersect a `BoundingBox`
+///
+/// This is the same as `cover_bounding_box`.
+///
+/// # Example
+///
+/// ```
+/// let b=geohashrust::decode("wtw3");
+/// assert_eq!(geohashrust::geohashes_in_box(&b, 5), geohashrust::cover_bounding_box(&b, 5));
+/// ```
+pub fn geohashes_in_box(bbox: &BoundingBox, precision: u8) -> Vec<String> {
+    cover_bounding_box(bbox, precision)
+}
+
+/// Get the GeoHashes with given precision covering exactly one of two `BoundingBox`es
+///
+/// These are the cells `cover_bounding_box` returns for one box but not the other,
+/// found by checking the cells of each box against the rows and columns of the other,
+/// so the work is bounded by the sizes of the two coverings. The cells of `a` come
+/// first, then the ones of `b`, each ordered row by row from the bottom-left corner.
+///
+/// # Example
+///
+/// ```
+/// let a=geohashrust::decode("wtw3");
+/// let b=geohashrust::BoundingBox::merged(&a, &geohashrust::decode("wtw6"));
+/// let cells=geohashrust::cover_symmetric_difference(&a, &b, 4);
+/// assert_eq!(cells, vec!["wtw6"]);
+/// ```
+pub fn cover_symmetric_difference(a: &BoundingBox, b: &BoundingBox, precision: u8) -> Vec<String> {
+    let grid_a = CellGrid::new(a, precision);
+    let grid_b = CellGrid::new(b, precision);
+    let mut output = grid_a.hashes(|row, col| !grid_b.contains(row, col));
+    output.extend(grid_b.hashes(|row, col| !grid_a.contains(row, col)));
+    output
+}
+
+/// Get all GeoHashes with given precision whose cells overlap a circle, with the radius
+/// in meters
+///
+/// The cells are ordered row by row from the bottom-left corner. The circle is clipped
+/// at the antimeridian.
+///
+/// # Example
+///
+/// ```
+/// let center=geohashrust::decode("wtw3s").center();
+/// assert_eq!(geohashrust::cover_circle(&center, 10.0, 5), vec!["wtw3s"]);
+/// assert_eq!(geohashrust::cover_circle(&center, 3000.0, 5).len(), 5);
+/// assert_eq!(geohashrust::cover_circle(&center, 4000.0, 5).len(), 9);
+/// ```
+pub fn cover_circle(center: &GeoLocation, radius_m: f64, precision: u8) -> Vec<String> {
+    let grid = CellGrid::new(&BoundingBox::from_center_radius(center, radius_m), precision);
+    grid.hashes(|row, col| grid.cell(row, col).distance_to_point(center) * 1000.0 <= radius_m)
+}
+
+/// Get all GeoHashes with given precision whose cells lie entirely within a circle,
+/// with the radius in meters
+///
+/// A cell is inside when all of its four corners are within the radius, cells
+/// crossing the circle are left out. The cells are ordered row by row from the
+/// bottom-left corner.
+///
+/// # Example
+///
+/// ```
+/// let center=geohashrust::decode("wtw3s").center();
+/// assert!(geohashrust::cells_inside_circle(&center, 1000.0, 5).is_empty());
+/// assert_eq!(geohashrust::cells_inside_circle(&center, 5000.0, 5), vec!["wtw3s"]);
+/// ```
+pub fn cells_inside_circle(center: &GeoLocation, radius_m: f64, precision: u8) -> Vec<String> {
+    let grid = CellGrid::new(&BoundingBox::from_center_radius(center, radius_m), precision);
+    grid.hashes(|row, col| {
+        let cell = grid.cell(row, col);
+        [cell.top_left(), cell.top_right(), cell.bottom_left(), cell.bottom_right()].iter()
+            .all(|corner| center.distance_to(corner) * 1000.0 <= radius_m)
+    })
+}
+
+/// Get the ratio of the area of a `BoundingBox` to the total area of its covering cells
+///
+/// The result is in `(0, 1]`, the closer to 1 the less area is wasted by covering the
+/// box with GeoHashes of given precision. A degenerate box with no area, e.g. a single
+/// point, gives 0.
+///
+/// # Example
+///
+/// ```
+/// let b=geohashrust::decode("wtw3s");
+/// assert!((geohashrust::coverage_efficiency(&b, 5)-1.0).abs() < 1e-9);
+/// ```
+pub fn coverage_efficiency(bbox: &BoundingBox, precision: u8) -> f64 {
+    let covered: f64 = cover_bounding_box(bbox, precision).iter()
+        .map(|h| decode(h).area())
+        .sum();
+    bbox.area() / covered
+}
+
+/// Get the ratio of the area of a circle to the total area of the cells from
+/// `cover_circle`, with the radius in meters
+///
+/// The result is in `(0, 1]` and grows with the precision, the closer to 1 the less
+/// area is wasted by covering the circle with GeoHashes of given precision.
+///
+/// # Example
+///
+/// ```
+/// let center=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// let coarse=geohashrust::circle_coverage_ratio(&center, 5000.0, 4);
+/// let fine=geohashrust::circle_coverage_ratio(&center, 5000.0, 7);
+/// assert!(coarse < 0.1);
+/// assert!(fine > 0.9 && fine <= 1.0);
+/// ```
+pub fn circle_coverage_ratio(center: &GeoLocation, radius_m: f64, precision: u8) -> f64 {
+    // Area of the spherical cap
+    let angle = radius_m / (EARTH_RADIUS * 1000.0);
+    let circle = 2.0 * PI * EARTH_RADIUS * EARTH_RADIUS * (1.0 - angle.cos());
+    let covered: f64 = cover_circle(center, radius_m, precision).iter()
+        .map(|h| decode(h).area())
+        .sum();
+    circle / covered
+}
+
+/// Compare two coverings, returning the GeoHashes only in `new` and those only in `old`
+///
+/// The output keeps the order of the input slices, with duplicates removed.
+///
+/// # Example
+///
+/// ```
+/// let old=vec!["wtw3s".to_string(), "wtw3t".to_string()];
+/// let new=vec!["wtw3t".to_string(), "wtw3w".to_string()];
+/// let (added, removed)=geohashrust::covering_diff(&old, &new);
+/// assert_eq!(added, vec!["wtw3w"]);
+/// assert_eq!(removed, vec!["wtw3s"]);
+/// ```
+pub fn covering_diff(old: &[String], new: &[String]) -> (Vec<String>, Vec<String>) {
+    let old_set: HashSet<&str> = old.iter().map(|h| h.as_str()).collect();
+    let new_set: HashSet<&str> = new.iter().map(|h| h.as_str()).collect();
+
+    let mut seen = HashSet::new();
+    let added = new.iter()
+        .filter(|h| !old_set.contains(h.as_str()) && seen.insert(h.as_str()))
+        .cloned()
+        .collect();
+    let mut seen = HashSet::new();
+    let removed = old.iter()
+        .filter(|h| !new_set.contains(h.as_str()) && seen.insert(h.as_str()))
+        .cloned()
+        .collect();
+    (added, removed)
+}
+
+/// Get the area-weighted average of the centers of the cells in a covering
+///
+/// Each cell counts by its area on the sphere, so cells of different precision, or
+/// spanning different latitudes, are weighted fairly. Coordinates are averaged
+/// directly, which is meant for coverings not crossing the antimeridian. Returns
+/// `None` for an empty covering.
+///
+/// # Example
+///
+/// ```
+/// let hashes=geohashrust::cover_bounding_box(&geohashrust::decode("wtw3"), 5);
+/// let c=geohashrust::covering_centroid(&hashes).unwrap();
+/// let center=geohashrust::decode("wtw3").center();
+/// assert!((c.longitude-center.longitude).abs() < 1e-9);
+/// ```
+pub fn covering_centroid(hashes: &[String]) -> Option<GeoLocation> {
+    if hashes.is_empty() {
+        return None;
+    }
+    let mut total = 0.0;
+    let mut latitude = 0.0;
+    let mut longitude = 0.0;
+    for h in hashes.iter() {
+        let b = decode(h);
+        let area = b.area();
+        let center = b.center();
+        total += area;
+        latitude += center.latitude * area;
+        longitude += center.longitude * area;
+    }
+    Some(GeoLocation {
+        latitude: latitude / total,
+        longitude: longitude / total,
+    })
+}
+
+/// Get the Jaccard similarity of two coverings, the number of GeoHashes in both over
+/// the number of GeoHashes in either
+///
+/// Duplicates are ignored. Two empty coverings are the same, with a similarity of 1.
+///
+/// # Example
+///
+/// ```
+/// let a=vec!["wtw3s".to_string(), "wtw3t".to_string()];
+/// let b=vec!["wtw3t".to_string(), "wtw3w".to_string()];
+/// assert!((geohashrust::covering_jaccard(&a, &b)-1.0/3.0).abs() < 1e-12);
+/// ```
+pub fn covering_jaccard(a: &[String], b: &[String]) -> f64 {
+    let a: HashSet<&str> = a.iter().map(|h| h.as_str()).collect();
+    let b: HashSet<&str> = b.iter().map(|h| h.as_str()).collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// Find the pairs of GeoHashes in a covering where one cell contains the other
+///
+/// A GeoHash contains another when it's a prefix of it, duplicates count too. The
+/// pairs are given as indices into `hashes`, the smaller first, in ascending order.
+///
+/// # Example
+///
+/// ```
+/// let hashes=vec!["wtw3s".to_string(), "wtw3".to_string(), "wtw37".to_string()];
+/// assert_eq!(geohashrust::find_overlaps(&hashes), vec![(0, 1), (1, 2)]);
+/// ```
+pub fn find_overlaps(hashes: &[String]) -> Vec<(usize, usize)> {
+    let lower: Vec<String> = hashes.iter().map(|h| h.to_ascii_lowercase()).collect();
+    // Sorted, the GeoHashes inside a cell directly follow it
+    let mut order: Vec<usize> = (0..lower.len()).collect();
+    order.sort_by(|&a, &b| lower[a].cmp(&lower[b]));
+
+    let mut output = Vec::new();
+    for (n, &i) in order.iter().enumerate() {
+        for &j in order[n+1..].iter().take_while(|&&j| lower[j].starts_with(&lower[i])) {
+            output.push((i.min(j), i.max(j)));
+        }
+    }
+    output.sort();
+    output
+}
+
+// Size of the header of a serialized covering
+static COVERING_HEADER_LEN: usize = 6;
+
+/// Pack a covering of GeoHashes with the same precision into bytes
+///
+/// The format is, with multi-byte integers in little endian:
+///
+/// | Offset | Size | Content                                            |
+/// |--------|------|----------------------------------------------------|
+/// | 0      | 1    | precision `p`, in characters                       |
+/// | 1      | 1    | length `l` of the prefix shared by all GeoHashes   |
+/// | 2      | 4    | number of GeoHashes `n`                            |
+/// | 6      | ...  | bit stream, most significant bit first             |
+///
+/// The bit stream holds the `5*l` bits of the shared prefix followed by the `5*(p-l)`
+/// bits of the rest of every GeoHash, padded with zeros to a whole byte. With more
+/// than one GeoHash the prefix is kept shorter than the GeoHashes, so every one of
+/// them takes up some bits and `n` is bounded by the size of the data.
+///
+/// # Panics
+///
+/// Panics if the GeoHashes have different lengths, are empty while there are more
+/// than one of them, or contain characters outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let hashes=geohashrust::cover_bounding_box(&geohashrust::decode("wtw3"), 6);
+/// let bytes=geohashrust::serialize_covering(&hashes);
+/// assert_eq!(bytes.len(), 6+(5*4+1024*5*2+7)/8);
+/// assert_eq!(geohashrust::deserialize_covering(&bytes).unwrap(), hashes);
+/// ```
+pub fn serialize_covering(hashes: &[String]) -> Vec<u8> {
+    let precision = hashes.first().map_or(0, |h| h.len());
+    assert!(hashes.iter().all(|h| h.len() == precision), "GeoHashes in a covering must have the same precision");
+    assert!(precision <= 255, "GeoHash too long");
+    assert!(hashes.len() <= u32::MAX as usize, "Too many GeoHashes");
+    assert!(precision > 0 || hashes.len() <= 1, "GeoHashes in a covering can't be empty");
+
+    let prefix_len = match hashes.first() {
+        Some(first) => hashes.iter().fold(precision, |l, h| {
+            first.bytes().zip(h.bytes()).take(l).take_while(|&(a, b)| a == b).count()
+        }),
+        None => 0,
+    };
+    // Leave a character to every GeoHash, even duplicates
+    let prefix_len = if hashes.len() > 1 { prefix_len.min(precision - 1) } else { prefix_len };
+
+    let mut output = Vec::with_capacity(COVERING_HEADER_LEN + (5 * (prefix_len + hashes.len() * (precision - prefix_len)) + 7) / 8);
+    output.push(precision as u8);
+    output.push(prefix_len as u8);
+    let count = hashes.len() as u32;
+    for shift in 0..4 {
+        output.push((count >> (8 * shift)) as u8);
+    }
+
+    let mut nbits = 0;
+    {
+        let mut push_char = |c: char| {
+            let index = match char_index(c) {
+                Ok(i) => i,
+                Err(e) => panic!("{}", e),
+            };
+            for bits in (0..5).rev() {
+                if nbits % 8 == 0 {
+                    output.push(0);
+                }
+                if (index >> bits) & 1 == 1 {
+                    *output.last_mut().unwrap() |= 0x80 >> (nbits % 8);
+                }
+                nbits += 1;
+            }
+        };
+        if let Some(first) = hashes.first() {
+            first.chars().take(prefix_len).for_each(&mut push_char);
+        }
+        for h in hashes.iter() {
+            h.chars().skip(prefix_len).for_each(&mut push_char);
+        }
+    }
+    output
+}
+
+/// Unpack a covering packed by `serialize_covering`
+///
+/// # Example
+///
+/// ```
+/// let hashes=vec!["wtw3s".to_string(), "wtw37".to_string()];
+/// let bytes=geohashrust::serialize_covering(&hashes);
+/// assert_eq!(geohashrust::deserialize_covering(&bytes).unwrap(), hashes);
+/// assert!(geohashrust::deserialize_covering(&bytes[..7]).is_err());
+/// ```
+pub fn deserialize_covering(bytes: &[u8]) -> Result<Vec<String>, GeohashError> {
+    if bytes.len() < COVERING_HEADER_LEN {
+        return Err(GeohashError::MalformedData);
+    }
+    let precision = bytes[0] as usize;
+    let prefix_len = bytes[1] as usize;
+    let count = bytes[2..COVERING_HEADER_LEN].iter().rev().fold(0usize, |n, &b| (n << 8) | b as usize);
+    if prefix_len > precision || (count == 0 && prefix_len != 0) {
+        return Err(GeohashError::MalformedData);
+    }
+    let suffix_len = precision - prefix_len;
+    // Without any bits of their own, more than one GeoHash would be unbounded by the data
+    if suffix_len == 0 && count > 1 {
+        return Err(GeohashError::MalformedData);
+    }
+    let total_bits = count.checked_mul(suffix_len)
+        .and_then(|n| n.checked_add(prefix_len))
+        .and_then(|n| n.checked_mul(5))
+        .ok_or(GeohashError::MalformedData)?;
+    let stream = &bytes[COVERING_HEADER_LEN..];
+    if stream.len() != total_bits / 8 + usize::from(total_bits % 8 != 0) {
+        return Err(GeohashError::MalformedData);
+    }
+
+    let mut nbits = 0;
+    let mut read_chars = |n: usize, out: &mut String| {
+        for _ in 0..n {
+            let mut index = 0;
+            for _ in 0..5 {
+                let bit = (stream[nbits / 8] >> (7 - nbits % 8)) & 1;
+                index = (index << 1) | bit as usize;
+                nbits += 1;
+            }
+            out.push(BASE32_CODES[index]);
+        }
+    };
+
+    let mut prefix = String::with_capacity(prefix_len);
+    read_chars(prefix_len, &mut prefix);
+    let mut output = Vec::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let mut h = String::with_capacity(precision);
+        h.push_str(&prefix);
+        read_chars(suffix_len, &mut h);
+        output.push(h);
+    }
+    Ok(output)
+}
@@ -0,0 +1,63 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors reported by the fallible GeoHash functions
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GeohashError {
+    /// The GeoHash has no characters
+    EmptyHash,
+    /// The GeoHash contains a character outside of the base32 alphabet
+    InvalidCharacter(char),
+    /// The latitude is outside of `[-90, 90]` degrees
+    InvalidLatitude(f64),
+    /// The longitude is outside of `[-180, 180]` degrees
+    InvalidLongitude(f64),
+    /// Serialized data or text is truncated, inconsistent or can't be parsed
+    MalformedData,
+    /// A `BinaryHash` already holds the most bits it can, 255
+    PrecisionOverflow,
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeohashError::EmptyHash => write!(f, "Empty GeoHash"),
+            GeohashError::InvalidCharacter(c) => write!(f, "Invalid GeoHash character {:?}", c),
+            GeohashError::InvalidLatitude(lat) => write!(f, "Latitude {} out of range", lat),
+            GeohashError::InvalidLongitude(lon) => write!(f, "Longitude {} out of range", lon),
+            GeohashError::MalformedData => write!(f, "Malformed data"),
+            GeohashError::PrecisionOverflow => write!(f, "BinaryHash can't hold more than 255 bits"),
+        }
+    }
+}
+
+impl Error for GeohashError {}
+
+/// Errors reported when creating a `GeoLocation` from coordinates out of range
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CoordinateError {
+    /// The latitude is outside of `[-90, 90]` degrees
+    InvalidLatitude(f64),
+    /// The longitude is outside of `[-180, 180]` degrees
+    InvalidLongitude(f64),
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CoordinateError::InvalidLatitude(lat) => write!(f, "Latitude {} out of range", lat),
+            CoordinateError::InvalidLongitude(lon) => write!(f, "Longitude {} out of range", lon),
+        }
+    }
+}
+
+impl Error for CoordinateError {}
+
+impl From<CoordinateError> for GeohashError {
+    fn from(e: CoordinateError) -> GeohashError {
+        match e {
+            CoordinateError::InvalidLatitude(lat) => GeohashError::InvalidLatitude(lat),
+            CoordinateError::InvalidLongitude(lon) => GeohashError::InvalidLongitude(lon),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use rand::Rng;
+
+use geolocation::GeoLocation;
+use geohash::{encode, decode};
+
+/// Get a random location in the GeoHash cell with given precision containing a point
+///
+/// This obscures the exact position while keeping it in the right neighborhood, the
+/// coarser the precision the larger the neighborhood. Requires the `rand` feature.
+///
+/// # Example
+///
+/// ```
+/// let p=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// let fuzzed=geohashrust::fuzz_location(&p, 5);
+/// assert!(geohashrust::decode("wtw3r").contains(&fuzzed));
+/// ```
+pub fn fuzz_location(point: &GeoLocation, precision: u8) -> GeoLocation {
+    let cell = decode(&encode(point, precision));
+    let mut rng = rand::thread_rng();
+    GeoLocation {
+        latitude: cell.min_lat + rng.gen::<f64>() * cell.latitude_range(),
+        longitude: cell.min_lon + rng.gen::<f64>() * cell.longitude_range(),
+    }
+}
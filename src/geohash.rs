@@ -1,7 +1,11 @@
-use geolocation::GeoLocation;
+use std::collections::HashSet;
+use std::fmt;
+
+use geolocation::{GeoLocation, EARTH_RADIUS};
 use boundingbox::BoundingBox;
+use error::{GeohashError, CoordinateError};
 
-static BASE32_CODES: [char; 32] = [
+pub(crate) static BASE32_CODES: [char; 32] = [
     '0', '1', '2', '3', '4', '5', '6', '7',
     '8', '9', 'b', 'c', 'd', 'e', 'f', 'g',
     'h', 'j', 'k', 'm', 'n', 'p', 'q', 'r',
@@ -21,19 +25,85 @@ static BASE32_INDICES: [u8; 75]=[
 	  29,   30,   31,                               // 78-7A, 'x'..'z'
 ];
 
+// Neighbor lookup tables for hashes of even length, in the order of north, east,
+// south and west. Hashes of odd length swap north/east and south/west.
+static NEIGHBOR_CODES: [&str; 4] = [
+    "p0r21436x8zb9dcf5h7kjnmqesgutwvy",
+    "bc01fg45238967deuvhjyznpkmstqrwx",
+    "14365h7k9dcfesgujnmqp0r2twvyx8zb",
+    "238967debc01fg45kmstqrwxuvhjyznp",
+];
+
+// Border lookup tables for hashes of even length, same order as `NEIGHBOR_CODES`
+static BORDER_CODES: [&str; 4] = [
+    "prxz",
+    "bcfguvyz",
+    "028b",
+    "0145hjnp",
+];
+
+// Get the index into the neighbor/border tables for a cardinal direction and hash length
+fn table_index(cardinal: usize, len: usize) -> usize {
+    if len % 2 == 0 {
+        cardinal
+    } else {
+        [1, 0, 3, 2][cardinal]
+    }
+}
+
+/// Compass directions between adjacent geohash cells
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// Get the latitude and longitude steps of the direction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::Direction;
+    /// assert_eq!(Direction::N.offsets(), (1, 0));
+    /// assert_eq!(Direction::SW.offsets(), (-1, -1));
+    /// ```
+    pub fn offsets(&self) -> (i8, i8) {
+        match *self {
+            Direction::N => (1, 0),
+            Direction::NE => (1, 1),
+            Direction::E => (0, 1),
+            Direction::SE => (-1, 1),
+            Direction::S => (-1, 0),
+            Direction::SW => (-1, -1),
+            Direction::W => (0, -1),
+            Direction::NW => (1, -1),
+        }
+    }
+}
+
 /// Binary hash code for a given `GeoLocation` with specific precision
+///
+/// The bits are stored in four 64-bit words, so a `BinaryHash` can hold up to 255 bits
+/// and stays `Copy`.
 #[derive(Default, Clone, Copy, PartialEq)]
 pub struct BinaryHash {
-    bits : u64,
+    // Bits from the most significant one of the first word, unused bits are zero
+    bits : [u64; 4],
     precision : u8,
 }
 
-///
 impl BinaryHash {
     /// Create an empty `BinaryHash`
     pub fn new() -> BinaryHash {
         BinaryHash{
-            bits: 0u64,
+            bits: [0u64; 4],
             precision: 0u8,
         }
     }
@@ -77,8 +147,60 @@ impl BinaryHash {
         output
     }
 
+    /// Create a `BinaryHash` from a base32 GeoHash, 5 bits per character
+    ///
+    /// # Panics
+    ///
+    /// Panics if the GeoHash contains a character outside of the base32 alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bh=geohashrust::BinaryHash::from_geohash("wt");
+    /// assert_eq!(bh.to_string(), "1110011001");
+    /// ```
+    pub fn from_geohash(hash: &str) -> BinaryHash {
+        let mut output=BinaryHash::new();
+        for c in hash.chars() {
+            let index = match char_index(c) {
+                Ok(i) => i,
+                Err(e) => panic!("{}", e),
+            };
+            for bits in (0..5).rev() {
+                output.push(((index >> bits) & 1)==1);
+            }
+        }
+        output
+    }
+
+    /// Convert the binary hash into a base32 GeoHash, 5 bits per character
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the binary hash isn't a multiple of 5.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bh=geohashrust::BinaryHash::from_string("1110011001");
+    /// assert_eq!(bh.to_geohash(), "wt");
+    /// ```
+    pub fn to_geohash(&self) -> String {
+        assert!(self.precision % 5 == 0,
+            "BinaryHash of {} bits can't be converted into a GeoHash, the length must be a multiple of 5", self.precision);
+        let mut output = String::with_capacity(self.precision as usize / 5);
+        for c in 0..self.precision / 5 {
+            let index = (0..5).fold(0usize, |index, bit| index << 1 | self.test(c * 5 + bit) as usize);
+            output.push(BASE32_CODES[index]);
+        }
+        output
+    }
+
     /// Encode a `GeoLocation` into binary hash
     ///
+    /// `precision` is the number of bits, up to 255 which is the most a `BinaryHash`
+    /// holds.
+    ///
     /// # Example
     ///
     /// ```
@@ -186,22 +308,6 @@ impl BinaryHash {
         output
     }
 
-    /// Convert `BinaryHash` to a `String`
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// let bh=geohashrust::BinaryHash::from_string("11100110");
-    /// assert_eq!(bh.to_string(), "11100110");
-    /// ```
-    pub fn to_string(&self) -> String {
-        let mut output=String::with_capacity(self.precision as usize);
-        for n in 0..self.precision {
-            output.push(if self.test(n) {'1'} else {'0'})
-        }
-        output
-    }
-
     /// Return the count of effective bits in the binary hash
     ///
     /// # Example
@@ -234,11 +340,29 @@ impl BinaryHash {
     /// assert!(!bh.empty());
     /// ```
     pub fn empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    /// Test if the binary hash is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut bh=geohashrust::BinaryHash::new();
+    /// assert!(bh.is_empty());
+    /// bh.push(false);
+    /// assert!(!bh.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
         self.precision == 0
     }
 
     /// Test specific bit of the binary hash
     ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't less than the length of the binary hash.
+    ///
     /// # Example
     ///
     /// ```
@@ -253,11 +377,17 @@ impl BinaryHash {
     /// assert!(bh.test(3));
     /// ```
     pub fn test(&self, n: u8) -> bool {
-        (self.bits & (1u64 << (self.precision-n-1))) != 0
+        assert!(n < self.precision, "Bit {} out of range", n);
+        let n = n as usize;
+        (self.bits[n / 64] & (1u64 << (63 - n % 64))) != 0
     }
 
     /// Push a bit into binary hash
     ///
+    /// # Panics
+    ///
+    /// Panics if the binary hash already holds 255 bits.
+    ///
     /// # Example
     ///
     /// ```
@@ -269,153 +399,1579 @@ impl BinaryHash {
     /// assert_eq!(bh.to_string(), "1101");
     /// ```
     pub fn push(&mut self, b: bool) {
-        self.bits <<= 1u64;
-        self.bits |= if b {1u64} else {0u64};
+        if let Err(e) = self.try_push(b) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Push a bit into binary hash, failing instead of panicking once it already holds
+    /// 255 bits
+    ///
+    /// The limit is the 255 bits a `BinaryHash` can hold, not the 64 bits of a single
+    /// word, and running into it is reported as `GeohashError::PrecisionOverflow` like
+    /// the other errors of the crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut bh=geohashrust::BinaryHash::encode(&geohashrust::GeoLocation::new(), 254);
+    /// assert!(bh.try_push(true).is_ok());
+    /// assert_eq!(bh.len(), 255);
+    /// assert_eq!(bh.try_push(true), Err(geohashrust::GeohashError::PrecisionOverflow));
+    /// assert_eq!(bh.len(), 255);
+    /// ```
+    pub fn try_push(&mut self, b: bool) -> Result<(), GeohashError> {
+        if self.precision == u8::MAX {
+            return Err(GeohashError::PrecisionOverflow);
+        }
+        let n = self.precision as usize;
+        if b {
+            self.bits[n / 64] |= 1u64 << (63 - n % 64);
+        }
         self.precision += 1u8;
+        Ok(())
+    }
+
+    /// Iterate over the bits of binary hash, from the first pushed one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a=geohashrust::BinaryHash::from_string("11100110");
+    /// let b=geohashrust::BinaryHash::from_string("11101110");
+    /// assert_eq!(a.iter().zip(b.iter()).position(|(x, y)| x != y), Some(4));
+    /// assert_eq!(a.iter().filter(|b| *b).count(), 5);
+    /// ```
+    pub fn iter(&self) -> BitIter<'_> {
+        BitIter {
+            hash: self,
+            index: 0,
+        }
+    }
+
+    /// Remove the last bit from binary hash and return it, or `None` if it's empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut bh=geohashrust::BinaryHash::from_string("110");
+    /// assert_eq!(bh.pop(), Some(false));
+    /// assert_eq!(bh.pop(), Some(true));
+    /// assert_eq!(bh.to_string(), "1");
+    /// ```
+    pub fn pop(&mut self) -> Option<bool> {
+        if self.precision == 0 {
+            return None;
+        }
+        let b = self.test(self.precision - 1);
+        self.truncate(self.precision - 1);
+        Some(b)
+    }
+
+    /// Drop the trailing bits of binary hash, keeping the first `new_precision` ones
+    ///
+    /// Does nothing if the binary hash isn't longer than `new_precision`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut bh=geohashrust::BinaryHash::from_string("11100110");
+    /// bh.truncate(5);
+    /// assert_eq!(bh.to_string(), "11100");
+    /// bh.truncate(6);
+    /// assert_eq!(bh.len(), 5);
+    /// ```
+    pub fn truncate(&mut self, new_precision: u8) {
+        if new_precision >= self.precision {
+            return;
+        }
+        let n = new_precision as usize;
+        for word in self.bits[(n + 63) / 64..].iter_mut() {
+            *word = 0;
+        }
+        if n % 64 != 0 {
+            self.bits[n / 64] &= !(u64::MAX >> (n % 64));
+        }
+        self.precision = new_precision;
     }
 }
 
-/// Encode a `GeoLocation` into GeoHash with given precision
+/// An iterator over the bits of a `BinaryHash`, created by `BinaryHash::iter`
+pub struct BitIter<'a> {
+    hash: &'a BinaryHash,
+    index: u8,
+}
+
+impl<'a> Iterator for BitIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.index >= self.hash.precision {
+            return None;
+        }
+        self.index += 1;
+        Some(self.hash.test(self.index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.hash.precision - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for BitIter<'a> {}
+
+impl<'a> IntoIterator for &'a BinaryHash {
+    type Item = bool;
+    type IntoIter = BitIter<'a>;
+
+    fn into_iter(self) -> BitIter<'a> {
+        self.iter()
+    }
+}
+
+/// Convert `BinaryHash` to a `String`
 ///
 /// # Example
 ///
 /// ```
-/// let l=geohashrust::GeoLocation{
-///         latitude:31.16373922,
-///         longitude:121.62585927,
-/// };
-/// assert_eq!(geohashrust::encode(&l, 7), "wtw3r9j");
+/// let bh=geohashrust::BinaryHash::from_string("11100110");
+/// assert_eq!(bh.to_string(), "11100110");
 /// ```
-pub fn encode(l: &GeoLocation, precision: u8) -> String {
-	let mut bbox = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
-    let mut islon = true;
-    let mut num_bits = 0;
-    let mut hash_index = 0;
-
-    // Pre-Allocate the hash string
-    let mut output=String::with_capacity(precision as usize);
-    
-    while output.len() < (precision as usize) {
-        if islon {
-            let mid = (bbox.max_lon + bbox.min_lon) / 2.0;
-            if l.longitude > mid {
-                hash_index = (hash_index << 1) + 1;
-                bbox.min_lon=mid;
-            } else {
-                hash_index = (hash_index << 1) + 0;
-                bbox.max_lon=mid;
-            }
-        } else {
-            let mid = (bbox.max_lat + bbox.min_lat) / 2.0;
-            if l.latitude > mid  {
-                hash_index = (hash_index << 1) + 1;
-                bbox.min_lat = mid;
-            } else {
-                hash_index = (hash_index << 1) + 0;
-                bbox.max_lat = mid;
-            }
+impl fmt::Display for BinaryHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self {
+            f.write_str(if b {"1"} else {"0"})?;
         }
-        islon = !islon;
+        Ok(())
+    }
+}
 
-        num_bits+=1;
-        if num_bits%5==0 {
-            output.push(BASE32_CODES[hash_index]);
-            hash_index = 0;
-        }
+/// Decode a `BinaryHash` into a `BoundingBox`
+///
+/// # Example
+///
+/// ```
+/// let bh=geohashrust::BinaryHash::from_string("11100110");
+/// let b: geohashrust::BoundingBox=bh.clone().into();
+/// assert!(b==bh.decode());
+/// ```
+impl From<BinaryHash> for BoundingBox {
+    fn from(bh: BinaryHash) -> BoundingBox {
+        bh.decode()
     }
-    output
 }
 
-/// Decode a GeoHash into a `BoundingBox`
+/// Decode a `BinaryHash` into a `BoundingBox`
 ///
 /// # Example
 ///
 /// ```
-/// let bbox=geohashrust::decode("wtw3r9jjz");
-/// assert!(bbox.contains(&geohashrust::GeoLocation::from_coordinates(31.163728, 121.625841)));
+/// let bh=geohashrust::BinaryHash::from_string("11100110");
+/// let b: geohashrust::BoundingBox=(&bh).into();
+/// assert!(b==bh.decode());
 /// ```
-pub fn decode(hash: &str) -> BoundingBox {
-    let mut output = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
-    let mut islon = true;
+impl<'a> From<&'a BinaryHash> for BoundingBox {
+    fn from(bh: &'a BinaryHash) -> BoundingBox {
+        bh.decode()
+    }
+}
 
-    for c in hash.chars() {
-        assert!(c>='0' && c<='z');
-        let char_index = BASE32_INDICES[(c as usize)-48];
-        assert!(char_index<32);
+/// Create a `BinaryHash` from a base32 GeoHash, same as `BinaryHash::from_geohash`
+///
+/// # Example
+///
+/// ```
+/// let bh: geohashrust::BinaryHash="wt".into();
+/// assert_eq!(bh.to_string(), "1110011001");
+/// ```
+impl<'a> From<&'a str> for BinaryHash {
+    fn from(hash: &'a str) -> BinaryHash {
+        BinaryHash::from_geohash(hash)
+    }
+}
 
-        for bits in (0..5).rev() {
-            let bit = ((char_index >> bits) & 1)==1;
-            if islon {
-                let mid = (output.max_lon + output.min_lon) / 2.0;
-                if bit {
-                    output.min_lon = mid;
-                } else {
-                    output.max_lon = mid;
-                }
-            } else {
-                let mid = (output.max_lat + output.min_lat) / 2.0;
-                if bit {
-                    output.min_lat = mid;
-                } else {
-                    output.max_lat = mid;
-                }
-            }
-            islon = !islon;
-        }
+// Precision of an `Encoder` unless set otherwise, in characters
+static ENCODER_DEFAULT_PRECISION: u8 = 12;
+
+/// A builder encoding a `GeoLocation` into GeoHashes, created by `GeoLocation::encoder`
+#[derive(Clone, Copy, PartialEq)]
+pub struct Encoder {
+    location: GeoLocation,
+    precision: u8,
+}
+
+impl Encoder {
+    /// Set the precision in characters, 12 by default
+    pub fn precision(mut self, precision: u8) -> Encoder {
+        self.precision = precision;
+        self
+    }
+
+    /// Encode into a `BinaryHash` with 5 bits per character of precision
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is over 51 characters, which doesn't fit into 255 bits.
+    pub fn binary(&self) -> BinaryHash {
+        assert!(self.precision <= 51, "BinaryHash can't hold more than 51 characters");
+        BinaryHash::encode(&self.location, self.precision * 5)
+    }
+
+    /// Encode into a base32 GeoHash
+    pub fn string(&self) -> String {
+        encode(&self.location, self.precision)
+    }
+
+    /// Encode into a base32 GeoHash, along with the `BoundingBox` of its cell
+    pub fn with_box(&self) -> (String, BoundingBox) {
+        let hash = self.string();
+        let bbox = decode(&hash);
+        (hash, bbox)
     }
-    println!("min_lat:{}, max_lat:{}, min_long:{}, max_lon:{}", output.min_lat, output.max_lat, output.min_lon, output.max_lon);
-    output
 }
 
+impl GeoLocation {
+    /// Start encoding the location into GeoHashes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+    /// let encoder=l.encoder().precision(7);
+    /// assert_eq!(encoder.string(), "wtw3r9j");
+    /// assert_eq!(encoder.binary().len(), 35);
+    /// assert!(encoder.binary()==geohashrust::BinaryHash::from_geohash("wtw3r9j"));
+    /// let (hash, bbox)=encoder.with_box();
+    /// assert!(bbox.contains(&l) && bbox==geohashrust::decode(&hash));
+    /// ```
+    pub fn encoder(&self) -> Encoder {
+        Encoder {
+            location: *self,
+            precision: ENCODER_DEFAULT_PRECISION,
+        }
+    }
 
-/// Get the neighbor of GeoHash on specific direction
+    /// Get the GeoHash of the location with given precision as an integer key
+    ///
+    /// The key holds the `5 * precision` interleaved bits of the GeoHash, right-justified,
+    /// i.e. it's the same as `geohash_to_key(&encode(self, precision), 5 * precision)`.
+    /// Keys with the same precision sort in GeoHash order, so locations in the same cell
+    /// get the same key and nearby locations mostly get close keys, except across the
+    /// border of large cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is over 12, as 60 bits is the most fitting in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+    /// assert_eq!(l.geohash_key(2), 0b11100_11001);
+    /// assert_eq!(l.geohash_key(7), geohashrust::geohash_to_key("wtw3r9j", 35));
+    /// ```
+    pub fn geohash_key(&self, precision: u8) -> u64 {
+        assert!(precision <= 12, "GeoHash doesn't fit into the key");
+        match precision {
+            0 => 0,
+            _ => encode_morton(self) >> (64 - 5 * precision as u32),
+        }
+    }
+}
+
+/// Convert a GeoHash into an integer key of `bit_width` bits
+///
+/// The bits of the GeoHash are left-justified and padded with zeros, so keys of
+/// GeoHashes with the same length sort in the same order as the GeoHashes, and the
+/// keys of all descendants of a GeoHash form a contiguous range.
+///
+/// # Panics
+///
+/// Panics if `bit_width` is over 64 or can't hold all bits of the GeoHash.
 ///
 /// # Example
 ///
 /// ```
-/// assert_eq!(geohashrust::neighbor("wtw3s", (-1, -1)), "wtw37");
-/// assert_eq!(geohashrust::neighbor("wtw3sjj", (1, -1)), "wtw3sjk");
+/// assert_eq!(geohashrust::geohash_to_key("wt", 16), 0b1110011001_000000);
+/// assert_eq!(geohashrust::key_to_geohash(0b1110011001_000000, 16, 2), "wt");
 /// ```
-pub fn neighbor(hash: &str, direction: (i8, i8)) -> String {
-	let b = decode(hash);
-	let cp = b.center();
-    let gl=match direction {
-        (dlat, dlon) => GeoLocation::from_coordinates(
-            cp.latitude + b.latitude_range() * (dlat as f64),
-            cp.longitude + b.longitude_range() * (dlon as f64),
-        )
-    };
-	encode(&gl, hash.len() as u8)
+pub fn geohash_to_key(hash: &str, bit_width: u8) -> u64 {
+    let bh = BinaryHash::from_geohash(hash);
+    assert!(bit_width <= 64, "Key can't be wider than 64 bits");
+    assert!(bh.precision <= bit_width, "GeoHash doesn't fit into the key");
+    if bh.precision == 0 {
+        return 0;
+    }
+    bh.bits[0] >> (64 - bit_width)
 }
 
-/// Get a vector of neighbors for the GeoHash on all 8 directions, with itself as the first
+/// Convert an integer key made by `geohash_to_key` back into a GeoHash with given precision
+///
+/// # Panics
+///
+/// Panics if `bit_width` is over 64 or holds less than `5*precision` bits.
 ///
 /// # Example
 ///
 /// ```
-/// let ns=geohashrust::neighbors("wtw3s");
-/// assert_eq!(ns[0], "wtw3s");
-/// assert_eq!(ns[1], "wtw37");
-/// assert_eq!(ns[2], "wtw3k");
-/// assert_eq!(ns[3], "wtw3m");
-/// assert_eq!(ns[4], "wtw3e");
-/// assert_eq!(ns[5], "wtw3t");
-/// assert_eq!(ns[6], "wtw3g");
-/// assert_eq!(ns[7], "wtw3u");
-/// assert_eq!(ns[8], "wtw3v");
+/// let key=geohashrust::geohash_to_key("wtw3r9jjzyjc", 64);
+/// assert_eq!(geohashrust::key_to_geohash(key, 64, 12), "wtw3r9jjzyjc");
+/// assert_eq!(geohashrust::key_to_geohash(key, 64, 5), "wtw3r");
 /// ```
-pub fn neighbors(hash: &str) -> Box<Vec<String>> {
-	Box::new(vec![
-		hash.to_string(),
-		neighbor(hash, (-1, -1)),
-		neighbor(hash, (-1,  0)),
-		neighbor(hash, (-1,  1)),
-		neighbor(hash, ( 0, -1)),
-		neighbor(hash, ( 0,  1)),
-		neighbor(hash, ( 1, -1)),
-		neighbor(hash, ( 1,  0)),
-		neighbor(hash, ( 1,  1)),
-	])
+pub fn key_to_geohash(key: u64, bit_width: u8, precision: u8) -> String {
+    assert!(bit_width <= 64, "Key can't be wider than 64 bits");
+    assert!(precision as u32 * 5 <= bit_width as u32, "Key doesn't hold enough bits");
+    let mut output = String::with_capacity(precision as usize);
+    for n in 0..precision {
+        let shift = bit_width - 5 * (n + 1);
+        output.push(BASE32_CODES[((key >> shift) & 0x1f) as usize]);
+    }
+    output
+}
+
+/// Encode a `GeoLocation` relative to a reference GeoHash
+///
+/// The location is encoded with the precision of the reference and only the characters
+/// after the prefix it shares with the reference are returned. Use
+/// `reconstruct_geohash` to get the full GeoHash back.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::relative_geohash(&l, "wtw3r9jjzyjz"), "c");
+/// assert_eq!(geohashrust::relative_geohash(&l, "wtw3s0000000"), "r9jjzyjc");
+/// ```
+pub fn relative_geohash(point: &GeoLocation, reference: &str) -> String {
+    let hash = encode(point, reference.len() as u8);
+    let shared = hash.bytes()
+        .zip(reference.bytes().map(|b| b.to_ascii_lowercase()))
+        .take_while(|&(a, b)| a == b)
+        .count();
+    hash[shared..].to_string()
+}
+
+/// Rebuild a GeoHash from its reference and the delta returned by `relative_geohash`
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::reconstruct_geohash("wtw3r9jjzyjz", "c"), "wtw3r9jjzyjc");
+/// assert_eq!(geohashrust::reconstruct_geohash("wtw3r9jjzyjz", ""), "wtw3r9jjzyjz");
+/// ```
+pub fn reconstruct_geohash(reference: &str, delta: &str) -> String {
+    assert!(delta.len() <= reference.len(), "Delta is longer than its reference");
+    let mut output = reference[..reference.len()-delta.len()].to_string();
+    output.push_str(delta);
+    output
+}
+
+/// Get the GeoHash of the cell containing the cell of a GeoHash, i.e. the GeoHash
+/// without its last character, or `None` for an empty GeoHash
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::parent("wtw3s"), Some("wtw3".to_string()));
+/// assert_eq!(geohashrust::parent("w"), Some("".to_string()));
+/// assert_eq!(geohashrust::parent(""), None);
+/// ```
+pub fn parent(hash: &str) -> Option<String> {
+    let (last, _) = hash.char_indices().last()?;
+    Some(hash[..last].to_string())
+}
+
+/// Get the 32 GeoHashes of the cells within the cell of a GeoHash, i.e. the GeoHash
+/// followed by each base32 character, in base32 order
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let children=geohashrust::geohash_children("wtw3");
+/// assert_eq!(children.len(), 32);
+/// assert_eq!(children[0], "wtw30");
+/// assert_eq!(children[31], "wtw3z");
+/// ```
+pub fn geohash_children(hash: &str) -> Vec<String> {
+    for c in hash.chars() {
+        if let Err(e) = char_index(c) {
+            panic!("{}", e);
+        }
+    }
+    BASE32_CODES.iter().map(|c| format!("{}{}", hash, c)).collect()
+}
+
+/// Get the 4 corners of the parent cell of a GeoHash, in the same order as
+/// `BoundingBox::corners`
+///
+/// # Panics
+///
+/// Panics if the GeoHash is empty, or contains a character outside of the base32
+/// alphabet.
+///
+/// # Example
+///
+/// ```
+/// let corners=geohashrust::parent_corners("wtw3s");
+/// assert!(corners==geohashrust::decode("wtw3").corners());
+/// ```
+pub fn parent_corners(hash: &str) -> [GeoLocation; 4] {
+    match parent(hash) {
+        Some(p) => decode(&p).corners(),
+        None => panic!("{}", GeohashError::EmptyHash),
+    }
+}
+
+/// Get the longest prefix shared by 2 GeoHashes, i.e. the smallest cell containing both
+///
+/// Same as `common_prefix_all` with 2 GeoHashes: characters are compared
+/// case-insensitively and the prefix is returned in lowercase, empty if the first
+/// characters differ.
+///
+/// # Panics
+///
+/// Panics if a GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::common_prefix("wtw3r", "wtw3e"), "wtw3");
+/// assert_eq!(geohashrust::common_prefix("wtw3r", "xtw3r"), "");
+/// ```
+pub fn common_prefix(a: &str, b: &str) -> String {
+    common_prefix_all(&[a.to_string(), b.to_string()])
+}
+
+/// Get the longest prefix shared by all GeoHashes, i.e. the smallest cell containing
+/// all of them
+///
+/// Characters are compared case-insensitively and the prefix is returned in lowercase.
+/// The prefix of a single GeoHash is the GeoHash itself, the prefix of none is empty.
+///
+/// # Panics
+///
+/// Panics if a GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let hashes=vec!["wtw3r9j".to_string(), "wtw3s".to_string(), "WTW37".to_string()];
+/// assert_eq!(geohashrust::common_prefix_all(&hashes), "wtw3");
+/// ```
+pub fn common_prefix_all(hashes: &[String]) -> String {
+    for c in hashes.iter().flat_map(|h| h.chars()) {
+        if let Err(e) = char_index(c) {
+            panic!("{}", e);
+        }
+    }
+    let first = match hashes.first() {
+        Some(h) => h.to_ascii_lowercase(),
+        None => return String::new(),
+    };
+    let len = hashes.iter().skip(1).fold(first.len(), |len, h| {
+        first.bytes().zip(h.bytes()).take(len).take_while(|&(a, b)| a.eq_ignore_ascii_case(&b)).count()
+    });
+    first[..len].to_string()
+}
+
+/// Get the first and the last GeoHash of `target_length` characters starting with a
+/// prefix, in lexicographic order
+///
+/// All GeoHashes of that length inside the cell of the prefix, and no others, fall
+/// into the range, e.g. for a `BETWEEN` clause in SQL.
+///
+/// # Panics
+///
+/// Panics if `target_length` is shorter than the prefix, or the prefix contains a
+/// character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let (lo, hi)=geohashrust::geohash_range("wtw3", 7);
+/// assert_eq!(lo, "wtw3000");
+/// assert_eq!(hi, "wtw3zzz");
+/// ```
+pub fn geohash_range(prefix: &str, target_length: usize) -> (String, String) {
+    assert!(target_length >= prefix.len(), "Target length is shorter than the prefix");
+    for c in prefix.chars() {
+        if let Err(e) = char_index(c) {
+            panic!("{}", e);
+        }
+    }
+    let prefix = prefix.to_ascii_lowercase();
+    let padding = target_length - prefix.len();
+    let mut lo = String::with_capacity(target_length);
+    lo.push_str(&prefix);
+    let mut hi = lo.clone();
+    lo.extend(::std::iter::repeat(BASE32_CODES[0]).take(padding));
+    hi.extend(::std::iter::repeat(BASE32_CODES[31]).take(padding));
+    (lo, hi)
+}
+
+// Get the latitude and longitude extents in degrees of a cell with given precision
+pub(crate) fn cell_size(precision: u8) -> (f64, f64) {
+    let bits = precision as i32 * 5;
+    let lat_bits = bits / 2;
+    let lon_bits = bits - lat_bits;
+    (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(lon_bits))
+}
+
+/// Get the smallest precision whose cells are at most `max_cell_m` meters across at a latitude
+///
+/// The longer of the two cell axes is compared, precisions are capped at 12. Cells are
+/// widest at the equator, so latitude 0 gives a precision that's safe anywhere. Sizes
+/// of cells at the equator, from `cell_dimensions`, north-south by east-west:
+///
+/// | Precision | Cell size         |
+/// |-----------|-------------------|
+/// | 1         | 5004km x 5004km   |
+/// | 2         | 625km x 1251km    |
+/// | 3         | 156km x 156km     |
+/// | 4         | 19.5km x 39.1km   |
+/// | 5         | 4.89km x 4.89km   |
+/// | 6         | 611m x 1222m      |
+/// | 7         | 153m x 153m       |
+/// | 8         | 19.1m x 38.2m     |
+/// | 9         | 4.77m x 4.77m     |
+/// | 10        | 59.6cm x 119cm    |
+/// | 11        | 14.9cm x 14.9cm   |
+/// | 12        | 1.86cm x 3.73cm   |
+///
+/// A size between two rows of the table gets the finer precision, so the cells never
+/// exceed the size asked for. At the equator 100m gives precision 8, not 7, since
+/// precision 7 cells are 153m across. Likewise 1000m gives 7, not 6, since precision 6
+/// cells are 1222m wide.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::precision_for_cell_size(200.0, 0.0), 7);
+/// assert_eq!(geohashrust::precision_for_cell_size(100.0, 0.0), 8);
+/// assert_eq!(geohashrust::precision_for_cell_size(5000.0, 0.0), 5);
+/// // Precision 6 cells are 1222m wide at the equator, but only 611m at 60 degrees
+/// assert_eq!(geohashrust::precision_for_cell_size(1000.0, 0.0), 7);
+/// assert_eq!(geohashrust::precision_for_cell_size(1000.0, 60.0), 6);
+/// ```
+pub fn precision_for_cell_size(max_cell_m: f64, latitude: f64) -> u8 {
+    for precision in 1u8..12 {
+        let (height, width) = cell_dimensions(precision, latitude);
+        if height.max(width) <= max_cell_m {
+            return precision;
+        }
+    }
+    12
+}
+
+/// Get the north-south and east-west size in meters of cells with given precision at
+/// a latitude
+///
+/// # Example
+///
+/// ```
+/// let (height, width)=geohashrust::cell_dimensions(5, 0.0);
+/// assert!((height-4886.0).abs() < 1.0);
+/// assert!((width-4886.0).abs() < 1.0);
+/// assert!(geohashrust::cell_dimensions(5, 60.0).1 < width/1.9);
+/// ```
+pub fn cell_dimensions(precision: u8, latitude: f64) -> (f64, f64) {
+    let meters_per_degree = (EARTH_RADIUS * 1000.0).to_radians();
+    let lon_scale = latitude.to_radians().cos().max(0.0);
+    let (lat_size, lon_size) = cell_size(precision);
+    (lat_size * meters_per_degree, lon_size * meters_per_degree * lon_scale)
+}
+
+/// Get how many cells with given precision fit in a square kilometer at a latitude
+///
+/// This is 1km² divided by the area of a cell from `cell_dimensions`. For instance
+/// precision 7 cells at the equator are 152.7m x 152.7m, or 0.02332km², so about 42.9
+/// of them fit in a square kilometer. The estimate of about 170 sometimes given for
+/// precision 7 would take cells of about 77m x 77m, which no precision has.
+///
+/// # Example
+///
+/// ```
+/// let n=geohashrust::cells_per_km2(7, 0.0);
+/// assert!((n-42.88).abs() < 0.01);
+/// ```
+pub fn cells_per_km2(precision: u8, latitude: f64) -> f64 {
+    let (height, width) = cell_dimensions(precision, latitude);
+    1.0e6 / (height * width)
+}
+
+// Get the zoom level, maybe fractional, of slippy map tiles as wide as the longer
+// axis of cells with given precision at the equator
+fn zoom_level(precision: u8) -> f64 {
+    let (lat_size, lon_size) = cell_size(precision);
+    (360.0 / lat_size.max(lon_size)).log2()
+}
+
+/// Get the precision whose cells are closest in size to slippy map tiles at a zoom level
+///
+/// Cells and tiles are compared by width at the equator, on a logarithmic scale.
+/// Precisions are capped at 12.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::precision_for_zoom(0), 1);
+/// assert_eq!(geohashrust::precision_for_zoom(10), 4);
+/// assert_eq!(geohashrust::precision_for_zoom(18), 7);
+/// ```
+pub fn precision_for_zoom(zoom: u8) -> u8 {
+    (1u8..=12)
+        .min_by(|&a, &b| {
+            let da = (zoom_level(a) - zoom as f64).abs();
+            let db = (zoom_level(b) - zoom as f64).abs();
+            da.partial_cmp(&db).unwrap()
+        })
+        .unwrap()
+}
+
+/// Get the slippy map zoom level whose tiles are closest in size to cells with given
+/// precision, the inverse of `precision_for_zoom`
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::zoom_for_precision(4), 10);
+/// assert_eq!(geohashrust::precision_for_zoom(geohashrust::zoom_for_precision(7)), 7);
+/// ```
+pub fn zoom_for_precision(precision: u8) -> u8 {
+    zoom_level(precision).round() as u8
+}
+
+/// Get the north-south and east-west error of a GeoHash in meters, i.e. the
+/// half-extents of its cell measured at the center latitude
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let (ns, ew)=geohashrust::geohash_error_meters("s000000");
+/// assert!((ns-76.0).abs() < 1.0);
+/// assert!((ew-76.0).abs() < 1.0);
+/// ```
+pub fn geohash_error_meters(hash: &str) -> (f64, f64) {
+    let (height, width) = cell_dimensions(hash.len() as u8, decode(hash).center().latitude);
+    (height / 2.0, width / 2.0)
+}
+
+/// Encode a `GeoLocation` into the shortest GeoHash whose cell diagonal is at most
+/// `max_error_meters`
+///
+/// The diagonal is measured between the corners of the decoded cell, so it shrinks
+/// towards the poles. At the equator it's roughly 1,400km at precision 2, 221km at
+/// precision 3, 6.9km at precision 5, 216m at precision 7, 6.7m at precision 9 and
+/// 4.2cm at precision 12. Precisions are capped at 12, so the result may not satisfy
+/// the bound when it's smaller than that.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::encode_within(&l, 200.0), "wtw3r9jj");
+/// assert_eq!(geohashrust::encode_within(&l, 10000.0), "wtw3r");
+/// ```
+pub fn encode_within(l: &GeoLocation, max_error_meters: f64) -> String {
+    for precision in 1u8..12 {
+        let hash = encode(l, precision);
+        let b = decode(&hash);
+        if b.bottom_left().distance_to(&b.top_right()) * 1000.0 <= max_error_meters {
+            return hash;
+        }
+    }
+    encode(l, 12)
+}
+
+/// Encode a `GeoLocation` into GeoHash with given precision
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation{
+///         latitude:31.16373922,
+///         longitude:121.62585927,
+/// };
+/// assert_eq!(geohashrust::encode(&l, 7), "wtw3r9j");
+/// ```
+pub fn encode(l: &GeoLocation, precision: u8) -> String {
+    let mut output = String::with_capacity(precision as usize);
+    encode_into(l, precision, &mut output);
+    output
+}
+
+/// Encode a latitude/longitude pair into GeoHash with given precision
+///
+/// Same as `encode`, without building a `GeoLocation` first, out of range coordinates
+/// are reported as an error.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::encode_coords(31.16373922, 121.62585927, 7).unwrap(), "wtw3r9j");
+/// assert!(geohashrust::encode_coords(91.0, 121.62585927, 7).is_err());
+/// ```
+pub fn encode_coords(lat: f64, lon: f64, precision: u8) -> Result<String, CoordinateError> {
+    Ok(encode(&GeoLocation::try_from_coordinates(lat, lon)?, precision))
+}
+
+/// Encode a `GeoLocation` into GeoHash with given precision, writing into `out`
+///
+/// `out` is cleared first, its allocation is reused so encoding many locations
+/// through the same buffer doesn't allocate for each of them.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// let mut buffer=String::from("something else");
+/// geohashrust::encode_into(&l, 7, &mut buffer);
+/// assert_eq!(buffer, "wtw3r9j");
+/// ```
+pub fn encode_into(l: &GeoLocation, precision: u8, out: &mut String) {
+    encode_cell(l, precision, false, out)
+}
+
+/// Encode a `GeoLocation` into GeoHash with given precision, in uppercase
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::encode_upper(&l, 7), "WTW3R9J");
+/// ```
+pub fn encode_upper(l: &GeoLocation, precision: u8) -> String {
+    encode_with_case(l, precision, true)
+}
+
+/// Encode a `GeoLocation` into GeoHash with given precision, in uppercase if `upper` is set
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::encode_with_case(&l, 7, true), "WTW3R9J");
+/// assert_eq!(geohashrust::encode_with_case(&l, 7, false), "wtw3r9j");
+/// ```
+pub fn encode_with_case(l: &GeoLocation, precision: u8, upper: bool) -> String {
+    let mut output = String::with_capacity(precision as usize);
+    encode_cell(l, precision, upper, &mut output);
+    output
+}
+
+/// Encode a `GeoLocation` into GeoHash with given precision, by bit interleaving
+///
+/// Produces the same output as `encode`, but quantizes latitude and longitude once and
+/// interleaves their bits instead of bisecting the cell one bit at a time. Precisions
+/// over 12 don't fit into 64 bits and fall back to `encode`.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::encode_fast(&l, 7), "wtw3r9j");
+/// assert_eq!(geohashrust::encode_fast(&l, 7), geohashrust::encode(&l, 7));
+/// ```
+pub fn encode_fast(l: &GeoLocation, precision: u8) -> String {
+    if precision > 12 {
+        return encode(l, precision);
+    }
+    let code = encode_morton(l);
+    (0..precision as u32).map(|i| BASE32_CODES[((code >> (59 - 5 * i)) & 0x1f) as usize]).collect()
+}
+
+// Interleave the 30 bit cell indices of a location into the 60 high bits of a Z-order
+// code, longitude first, as the 60 bits of a 12 character GeoHash
+fn encode_morton(l: &GeoLocation) -> u64 {
+    (spread_bits(quantize(l.longitude, 180.0)) << 1 | spread_bits(quantize(l.latitude, 90.0))) << 4
+}
+
+// Index of the 30 bit cell containing `v` within [-range, range], with the same
+// tie-breaking as the bisection, a value on a border belongs to the lower cell.
+// Cell borders are multiples of a power of two, so they're exact in f64, the
+// estimate is corrected against them to cover rounding in the scaling.
+fn quantize(v: f64, range: f64) -> u32 {
+    const CELLS: u32 = 1 << 30;
+    let border = |i: u32| i as f64 * (2.0 * range / CELLS as f64) - range;
+    let mut i = (((v + range) / (2.0 * range) * CELLS as f64) as u32).min(CELLS - 1);
+    while i > 0 && v <= border(i) {
+        i -= 1;
+    }
+    while i < CELLS - 1 && v > border(i + 1) {
+        i += 1;
+    }
+    i
+}
+
+// Spread the low 32 bits of `v` to the even bits of a u64
+fn spread_bits(v: u32) -> u64 {
+    let mut x = v as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+// Narrow down the cell of a location into `output`, replacing its content
+fn encode_cell(l: &GeoLocation, precision: u8, upper: bool, output: &mut String) {
+	let mut bbox = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
+    let mut islon = true;
+    let mut num_bits = 0;
+    let mut hash_index = 0;
+
+    output.clear();
+    output.reserve(precision as usize);
+    
+    while output.len() < (precision as usize) {
+        if islon {
+            let mid = (bbox.max_lon + bbox.min_lon) / 2.0;
+            if l.longitude > mid {
+                hash_index = (hash_index << 1) + 1;
+                bbox.min_lon=mid;
+            } else {
+                hash_index <<= 1;
+                bbox.max_lon=mid;
+            }
+        } else {
+            let mid = (bbox.max_lat + bbox.min_lat) / 2.0;
+            if l.latitude > mid  {
+                hash_index = (hash_index << 1) + 1;
+                bbox.min_lat = mid;
+            } else {
+                hash_index <<= 1;
+                bbox.max_lat = mid;
+            }
+        }
+        islon = !islon;
+
+        num_bits+=1;
+        if num_bits%5==0 {
+            let c = BASE32_CODES[hash_index];
+            output.push(if upper {c.to_ascii_uppercase()} else {c});
+            hash_index = 0;
+        }
+    }
+}
+
+// Get the base32 index of a GeoHash character
+pub(crate) fn char_index(c: char) -> Result<u8, GeohashError> {
+    if !('0'..='z').contains(&c) {
+        return Err(GeohashError::InvalidCharacter(c));
+    }
+    let index = BASE32_INDICES[(c as usize)-48];
+    if index < 32 {
+        Ok(index)
+    } else {
+        Err(GeohashError::InvalidCharacter(c))
+    }
+}
+
+/// Test if a string is a valid GeoHash, i.e. it isn't empty and only has characters
+/// of the base32 alphabet
+///
+/// Letters are accepted in either case, like `decode` does. `a`, `i`, `l` and `o`
+/// aren't part of the alphabet in either case.
+///
+/// # Example
+///
+/// ```
+/// assert!(geohashrust::is_valid_geohash("wtw3r9jjz"));
+/// assert!(geohashrust::is_valid_geohash("WTW3R9JJZ"));
+/// assert!(!geohashrust::is_valid_geohash("wtw3l"));
+/// assert!(!geohashrust::is_valid_geohash(""));
+/// ```
+pub fn is_valid_geohash(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| char_index(c).is_ok())
+}
+
+// Narrow down the whole world into the cell of a GeoHash
+fn decode_cell(hash: &str) -> Result<BoundingBox, GeohashError> {
+    narrow_cell(hash, |_| true).map(|cell| cell.unwrap())
+}
+
+// Narrow down the whole world into the cell of a GeoHash bit by bit, giving up with
+// `None` as soon as `keep` rejects the cell narrowed down so far
+fn narrow_cell<F>(hash: &str, mut keep: F) -> Result<Option<BoundingBox>, GeohashError>
+    where F: FnMut(&BoundingBox) -> bool {
+    let mut output = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
+    if !keep(&output) {
+        return Ok(None);
+    }
+    let mut islon = true;
+
+    for c in hash.chars() {
+        let char_index = char_index(c)?;
+
+        for bits in (0..5).rev() {
+            let bit = ((char_index >> bits) & 1)==1;
+            if islon {
+                let mid = (output.max_lon + output.min_lon) / 2.0;
+                if bit {
+                    output.min_lon = mid;
+                } else {
+                    output.max_lon = mid;
+                }
+            } else {
+                let mid = (output.max_lat + output.min_lat) / 2.0;
+                if bit {
+                    output.min_lat = mid;
+                } else {
+                    output.max_lat = mid;
+                }
+            }
+            islon = !islon;
+            if !keep(&output) {
+                return Ok(None);
+            }
+        }
+    }
+    Ok(Some(output))
+}
+
+/// Test if the cell of the GeoHash `fine` is within the cell of `coarse`, i.e. `coarse`
+/// is a prefix of `fine`
+///
+/// Characters are compared case-insensitively, a GeoHash is within its own cell and an
+/// empty `coarse` is the whole world, which contains everything. Strings with a
+/// character outside of the base32 alphabet aren't GeoHashes and never match.
+///
+/// # Example
+///
+/// ```
+/// assert!(geohashrust::is_within_cell("wtw3r9j", "wtw3"));
+/// assert!(!geohashrust::is_within_cell("wtw3", "wtw3r9j"));
+/// assert!(!geohashrust::is_within_cell("wtw3r9j", "wtw6"));
+/// assert!(geohashrust::is_within_cell("wtw3r9j", ""));
+/// ```
+pub fn is_within_cell(fine: &str, coarse: &str) -> bool {
+    let valid = |s: &str| s.chars().all(|c| char_index(c).is_ok());
+    valid(fine) && valid(coarse) && fine.len() >= coarse.len()
+        && fine.as_bytes()[..coarse.len()].eq_ignore_ascii_case(coarse.as_bytes())
+}
+
+/// Test if the cell of a GeoHash contains a `GeoLocation`, borders included
+///
+/// Same as `decode(hash).contains(point)`, but the cell is narrowed down bit by bit and
+/// the test stops as soon as the point falls outside of it.
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(31.163728, 121.625841);
+/// assert!(geohashrust::geohash_contains("wtw3r9jjz", &l));
+/// assert!(!geohashrust::geohash_contains("wtw3s", &l));
+/// ```
+pub fn geohash_contains(hash: &str, point: &GeoLocation) -> bool {
+    // Invalid characters past where the test stops must panic too
+    for c in hash.chars() {
+        if let Err(e) = char_index(c) {
+            panic!("{}", e);
+        }
+    }
+    match narrow_cell(hash, |cell| cell.contains(point)) {
+        Ok(cell) => cell.is_some(),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Decode a GeoHash into a `BoundingBox`
+///
+/// Nothing is written to stdout or stderr, so it's fine to call in tight loops.
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet, use
+/// `try_decode` to handle invalid input.
+///
+/// # Example
+///
+/// ```
+/// let bbox=geohashrust::decode("wtw3r9jjz");
+/// assert!(bbox.contains(&geohashrust::GeoLocation::from_coordinates(31.163728, 121.625841)));
+/// ```
+pub fn decode(hash: &str) -> BoundingBox {
+    match decode_cell(hash) {
+        Ok(b) => b,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Decode a GeoHash into a `BoundingBox` along with its precision, the number of characters
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet, or is
+/// longer than 255 characters.
+///
+/// # Example
+///
+/// ```
+/// let (bbox, precision)=geohashrust::decode_full("wtw3r9jjz");
+/// assert_eq!(precision, 9);
+/// assert!(bbox==geohashrust::decode("wtw3r9jjz"));
+/// ```
+pub fn decode_full(hash: &str) -> (BoundingBox, u8) {
+    let b = decode(hash);
+    assert!(hash.len() <= u8::MAX as usize, "GeoHash too long");
+    (b, hash.len() as u8)
+}
+
+/// Decode a GeoHash into the `GeoLocation` at the center of its cell
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::decode_to_location("wtw3r9jjz");
+/// assert!(l==geohashrust::decode("wtw3r9jjz").center());
+/// ```
+pub fn decode_to_location(hash: &str) -> GeoLocation {
+    decode(hash).center()
+}
+
+/// Decode a GeoHash into the center of its cell along with the error margins
+///
+/// The second `GeoLocation` holds the `latitude_error` and `longitude_error` of the
+/// cell in degrees, i.e. the cell spans the center plus or minus the errors.
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let (center, error)=geohashrust::decode_with_error("s");
+/// assert_eq!((center.latitude, center.longitude), (22.5, 22.5));
+/// assert_eq!((error.latitude, error.longitude), (22.5, 22.5));
+/// ```
+pub fn decode_with_error(hash: &str) -> (GeoLocation, GeoLocation) {
+    let b = decode(hash);
+    let error = GeoLocation {
+        latitude: b.latitude_error(),
+        longitude: b.longitude_error(),
+    };
+    (b.center(), error)
+}
+
+/// Decode a GeoHash into a `BoundingBox`, failing on empty or malformed input
+///
+/// The GeoHash may be in any mix of upper and lower case, both cases of a letter decode
+/// to the same cell. `a`, `i`, `l` and `o` are rejected in both cases, with the
+/// offending character reported as it appears in the input.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeohashError;
+/// assert!(geohashrust::try_decode("wtw3r9jjz").is_ok());
+/// assert!(geohashrust::try_decode("WTW3R9J").unwrap()==geohashrust::decode("wtw3r9j"));
+/// assert_eq!(geohashrust::try_decode("wtw3a").err(), Some(GeohashError::InvalidCharacter('a')));
+/// assert_eq!(geohashrust::try_decode("WTW3A").err(), Some(GeohashError::InvalidCharacter('A')));
+/// assert_eq!(geohashrust::try_decode("").err(), Some(GeohashError::EmptyHash));
+/// ```
+pub fn try_decode(hash: &str) -> Result<BoundingBox, GeohashError> {
+    if hash.is_empty() {
+        return Err(GeohashError::EmptyHash);
+    }
+    decode_cell(hash)
+}
+
+/// Decode a GeoHash in any mix of upper and lower case into a `BoundingBox`
+///
+/// Both cases of a letter decode to the same cell, and `a`, `i`, `l` and `o` are
+/// rejected in both cases with the offending character as it appears in the input.
+/// This is what `try_decode` does too, the name just makes it explicit.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeohashError;
+/// let b=geohashrust::decode_case_insensitive("WtW3r9J").unwrap();
+/// assert!(b==geohashrust::decode("wtw3r9j"));
+/// assert_eq!(geohashrust::decode_case_insensitive("WTW3A").err(), Some(GeohashError::InvalidCharacter('A')));
+/// ```
+pub fn decode_case_insensitive(hash: &str) -> Result<BoundingBox, GeohashError> {
+    try_decode(hash)
+}
+
+/// Lazily decode a sequence of GeoHashes, reporting errors for each one separately
+///
+/// # Example
+///
+/// ```
+/// let boxes: Vec<_>=geohashrust::decode_many(vec!["wtw3s", "wtw3a", "wtw37"]).collect();
+/// assert!(boxes[0].is_ok());
+/// assert!(boxes[1].is_err());
+/// assert!(boxes[2].is_ok());
+/// ```
+pub fn decode_many<I, S>(hashes: I) -> impl Iterator<Item = Result<BoundingBox, GeohashError>>
+    where I: IntoIterator<Item = S>,
+          S: AsRef<str> {
+    hashes.into_iter().map(|h| try_decode(h.as_ref()))
+}
+
+/// Test if stepping from the GeoHash towards `dir` would change its prefix
+///
+/// This is the case when the last character lies on the border of its parent cell,
+/// for diagonal directions it's enough to lie on either of the two borders.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::Direction;
+/// assert!(geohashrust::is_edge_cell("wtw3z", Direction::N));
+/// assert!(!geohashrust::is_edge_cell("wtw3s", Direction::N));
+/// assert!(geohashrust::is_edge_cell("wtw3h", Direction::SW));
+/// assert!(!geohashrust::is_edge_cell("wtw3s", Direction::SW));
+/// ```
+pub fn is_edge_cell(hash: &str, dir: Direction) -> bool {
+    let last = match hash.chars().last() {
+        Some(c) => c.to_ascii_lowercase(),
+        None => return false,
+    };
+    let (dlat, dlon) = dir.offsets();
+    let on_border = |cardinal: usize| BORDER_CODES[table_index(cardinal, hash.len())].contains(last);
+    (dlat > 0 && on_border(0))
+        || (dlon > 0 && on_border(1))
+        || (dlat < 0 && on_border(2))
+        || (dlon < 0 && on_border(3))
+}
+
+/// Test if a whole GeoHash cell lies in a direction from a point
+///
+/// The edge of the cell facing the point is compared, a cell whose edge runs through
+/// the point still counts. For diagonal directions the cell has to lie in both
+/// directions, e.g. north and east for `Direction::NE`. Longitudes are compared as
+/// they are, without wrapping around the antimeridian.
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::{Direction, GeoLocation};
+/// let p=GeoLocation::from_coordinates(31.0, 121.0);
+/// assert!(geohashrust::cell_is_entirely("wtw3s", &p, Direction::N));
+/// assert!(geohashrust::cell_is_entirely("wtw3s", &p, Direction::NE));
+/// assert!(!geohashrust::cell_is_entirely("wtw3s", &p, Direction::S));
+/// ```
+pub fn cell_is_entirely(hash: &str, of: &GeoLocation, dir: Direction) -> bool {
+    let b = decode(hash);
+    let (dlat, dlon) = dir.offsets();
+    let lat_ok = match dlat {
+        1 => b.min_lat >= of.latitude,
+        -1 => b.max_lat <= of.latitude,
+        _ => true,
+    };
+    let lon_ok = match dlon {
+        1 => b.min_lon >= of.longitude,
+        -1 => b.max_lon <= of.longitude,
+        _ => true,
+    };
+    lat_ok && lon_ok
+}
+
+// Get the adjacent GeoHash towards a cardinal direction, in the order of the lookup
+// tables. Returns `None` when stepping north or south off the world.
+fn adjacent(hash: &str, cardinal: usize) -> Option<String> {
+    let last = match hash.chars().last() {
+        Some(c) => c.to_ascii_lowercase(),
+        None => return Some(String::new()),
+    };
+    if let Err(e) = char_index(last) {
+        panic!("{}", e);
+    }
+    let parent = &hash[..hash.len()-last.len_utf8()];
+    let table = table_index(cardinal, hash.len());
+
+    let mut output = if BORDER_CODES[table].contains(last) {
+        if parent.is_empty() {
+            // Longitude wraps around, latitude doesn't
+            if cardinal % 2 == 0 {
+                return None;
+            }
+            String::new()
+        } else {
+            adjacent(parent, cardinal)?
+        }
+    } else {
+        parent.to_ascii_lowercase()
+    };
+    output.push(BASE32_CODES[NEIGHBOR_CODES[table].find(last).unwrap()]);
+    Some(output)
+}
+
+// Get the GeoHash on the other side of the pole, i.e. shifted by 180 degrees in longitude
+fn across_pole(hash: &str) -> String {
+    let mut chars = hash.chars();
+    let first = match chars.next() {
+        Some(c) => match char_index(c) {
+            Ok(i) => i,
+            Err(e) => panic!("{}", e),
+        },
+        None => return String::new(),
+    };
+    let mut output = String::with_capacity(hash.len());
+    output.push(BASE32_CODES[(first ^ 0x10) as usize]);
+    output.extend(chars.map(|c| c.to_ascii_lowercase()));
+    output
+}
+
+/// Get the neighbor of GeoHash on specific direction
+///
+/// The direction is given as steps in latitude and longitude, the neighbor is found
+/// with the base32 lookup tables so it's exact for any precision. Longitude wraps
+/// around the antimeridian. Stepping north or south past a pole lands on the cell
+/// across the pole, at the longitude shifted by 180 degrees, and carries on in the
+/// opposite direction.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::neighbor("wtw3s", (-1, -1)), "wtw37");
+/// assert_eq!(geohashrust::neighbor("wtw3sjj", (1, -1)), "wtw3sjk");
+/// assert_eq!(geohashrust::neighbor("zzz", geohashrust::Direction::N.offsets()), "gzz");
+/// ```
+pub fn neighbor(hash: &str, direction: (i8, i8)) -> String {
+    let (dlat, dlon) = direction;
+    let mut output = hash.to_string();
+    let east_west = if dlon > 0 {1} else {3};
+    for _ in 0..dlon.unsigned_abs() {
+        output = adjacent(&output, east_west).unwrap();
+    }
+    let mut north_south = if dlat > 0 {0} else {2};
+    for _ in 0..dlat.unsigned_abs() {
+        output = match adjacent(&output, north_south) {
+            Some(h) => h,
+            None => {
+                north_south = 2 - north_south;
+                across_pole(&output)
+            }
+        };
+    }
+    output
+}
+
+/// Get a vector of neighbors for the GeoHash on all 8 directions, with itself as the first
+///
+/// # Example
+///
+/// ```
+/// let ns=geohashrust::neighbors("wtw3s");
+/// assert_eq!(ns[0], "wtw3s");
+/// assert_eq!(ns[1], "wtw37");
+/// assert_eq!(ns[2], "wtw3k");
+/// assert_eq!(ns[3], "wtw3m");
+/// assert_eq!(ns[4], "wtw3e");
+/// assert_eq!(ns[5], "wtw3t");
+/// assert_eq!(ns[6], "wtw3g");
+/// assert_eq!(ns[7], "wtw3u");
+/// assert_eq!(ns[8], "wtw3v");
+/// ```
+pub fn neighbors(hash: &str) -> Vec<String> {
+    let mut output = Vec::with_capacity(9);
+    output.push(hash.to_string());
+    output.extend(neighbor_iter(hash));
+    output
+}
+
+// Offsets of the neighbors yielded by `NeighborIter`, in the order of `neighbors`
+static NEIGHBOR_OFFSETS: [(i8, i8); 8] = [
+    (-1, -1),
+    (-1,  0),
+    (-1,  1),
+    ( 0, -1),
+    ( 0,  1),
+    ( 1, -1),
+    ( 1,  0),
+    ( 1,  1),
+];
+
+/// An iterator over the 8 neighbors of a GeoHash
+pub struct NeighborIter<'a> {
+    hash: &'a str,
+    // The neighbors to the west and the east, shared by the diagonal neighbors
+    west: String,
+    east: String,
+    index: usize,
+}
+
+impl<'a> Iterator for NeighborIter<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let (dlat, dlon) = *NEIGHBOR_OFFSETS.get(self.index)?;
+        self.index += 1;
+        let column = match dlon {
+            -1 => &self.west,
+            1 => &self.east,
+            _ => self.hash,
+        };
+        Some(if dlat == 0 { column.to_string() } else { neighbor(column, (dlat, 0)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = NEIGHBOR_OFFSETS.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for NeighborIter<'a> {}
+
+/// Iterate over the neighbors of the GeoHash on all 8 directions, without the GeoHash itself
+///
+/// The neighbors are computed one at a time, in the same order as `neighbors`. The
+/// neighbors to the west and the east are found once up front, the others are one step
+/// north or south of them or of the GeoHash itself.
+///
+/// # Example
+///
+/// ```
+/// let mut it=geohashrust::neighbor_iter("wtw3s");
+/// assert_eq!(it.size_hint(), (8, Some(8)));
+/// assert_eq!(it.next().unwrap(), "wtw37");
+/// assert_eq!(it.len(), 7);
+/// assert!(geohashrust::neighbor_iter("wtw3s").any(|n| n == "wtw3v"));
+/// ```
+pub fn neighbor_iter(hash: &str) -> NeighborIter<'_> {
+    NeighborIter {
+        hash,
+        west: neighbor(hash, (0, -1)),
+        east: neighbor(hash, (0, 1)),
+        index: 0,
+    }
+}
+
+/// Get the neighbors for the GeoHash on all 8 directions, clockwise from the north
+///
+/// The order is N, NE, E, SE, S, SW, W, NW, same as `Direction`.
+///
+/// # Example
+///
+/// ```
+/// let ns=geohashrust::neighbors_clockwise("wtw3s");
+/// assert_eq!(ns[0], "wtw3u");
+/// assert_eq!(ns[2], "wtw3t");
+/// assert_eq!(ns[5], "wtw37");
+/// ```
+pub fn neighbors_clockwise(hash: &str) -> [String; 8] {
+    [
+        neighbor(hash, Direction::N.offsets()),
+        neighbor(hash, Direction::NE.offsets()),
+        neighbor(hash, Direction::E.offsets()),
+        neighbor(hash, Direction::SE.offsets()),
+        neighbor(hash, Direction::S.offsets()),
+        neighbor(hash, Direction::SW.offsets()),
+        neighbor(hash, Direction::W.offsets()),
+        neighbor(hash, Direction::NW.offsets()),
+    ]
+}
+
+/// Get the 4 neighbors sharing an edge with the GeoHash, in the order N, S, E, W
+///
+/// # Example
+///
+/// ```
+/// let ns=geohashrust::edge_neighbors("wtw3s");
+/// assert_eq!(ns, ["wtw3u", "wtw3k", "wtw3t", "wtw3e"]);
+/// ```
+pub fn edge_neighbors(hash: &str) -> [String; 4] {
+    [
+        neighbor(hash, Direction::N.offsets()),
+        neighbor(hash, Direction::S.offsets()),
+        neighbor(hash, Direction::E.offsets()),
+        neighbor(hash, Direction::W.offsets()),
+    ]
+}
+
+/// Get the neighbors of the GeoHash overlapping a `BoundingBox`
+///
+/// The neighbors are in the same order as `neighbors`, without the GeoHash itself.
+/// Cells touching only the border of the box are included.
+///
+/// # Example
+///
+/// ```
+/// let b=geohashrust::decode("wtw3s");
+/// let c=b.center();
+/// let bbox=geohashrust::BoundingBox::from_coordinates(c.latitude, c.latitude+1.0, c.longitude, c.longitude+1.0);
+/// assert_eq!(geohashrust::neighbors_in_box("wtw3s", &bbox), vec!["wtw3t", "wtw3u", "wtw3v"]);
+/// ```
+pub fn neighbors_in_box(hash: &str, bbox: &BoundingBox) -> Vec<String> {
+    neighbors(hash).into_iter()
+        .skip(1)
+        .filter(|n| bbox.intersects(&decode(n)))
+        .collect()
+}
+
+/// Check if 2 GeoHashes are neighbors, including diagonal ones
+///
+/// The neighbors are found with the lookup tables, so cells sharing only a corner or
+/// an edge across the antimeridian are adjacent too. GeoHashes of different precision,
+/// empty or invalid ones are never adjacent, and a GeoHash isn't adjacent to itself.
+///
+/// # Example
+///
+/// ```
+/// assert!(geohashrust::are_adjacent("wtw3s", "wtw37"));
+/// assert!(!geohashrust::are_adjacent("wtw3s", "wtw3s"));
+/// assert!(!geohashrust::are_adjacent("wtw3s", "wtw3"));
+/// ```
+pub fn are_adjacent(a: &str, b: &str) -> bool {
+    if a.is_empty() || a.len() != b.len() || a.chars().chain(b.chars()).any(|c| char_index(c).is_err()) {
+        return false;
+    }
+    let b = b.to_ascii_lowercase();
+    if a.eq_ignore_ascii_case(&b) {
+        return false;
+    }
+    neighbors(a).iter().skip(1).any(|n| *n == b)
+}
+
+/// Get the GeoHashes at exactly `k` steps away from the GeoHash, i.e. the border of the
+/// `(2k+1)x(2k+1)` block of cells centered on it
+///
+/// The cells start at the bottom-left corner and go counter-clockwise, the ring 0 is
+/// the GeoHash itself.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::ring("wtw3s", 0), vec!["wtw3s"]);
+/// let r=geohashrust::ring("wtw3s", 1);
+/// assert_eq!(r.len(), 8);
+/// assert_eq!(r[0], "wtw37");
+/// assert_eq!(r[1], "wtw3k");
+/// ```
+pub fn ring(hash: &str, k: u32) -> Vec<String> {
+    if k == 0 {
+        return vec![hash.to_string()];
+    }
+    let mut current = hash.to_string();
+    for _ in 0..k {
+        current = neighbor(&current, (-1, -1));
+    }
+    let mut output = Vec::with_capacity(8 * k as usize);
+    for step in [(0, 1), (1, 0), (0, -1), (-1, 0)].iter() {
+        for _ in 0..2*k {
+            output.push(current.clone());
+            current = neighbor(&current, *step);
+        }
+    }
+    output
+}
+
+/// Get all GeoHashes within `k` steps of the GeoHash, i.e. the `(2k+1)x(2k+1)` block of
+/// cells centered on it, including the GeoHash itself
+///
+/// The cells are ordered row by row from the bottom-left corner, each row going east.
+/// Longitude wraps around the antimeridian. Near the poles, or when the block is wider
+/// than the world, the same cell can be reached more than once, only its first
+/// occurrence is kept. With `k=1` these are the same 9 cells as `neighbors`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::neighbors_within("wtw3s", 0), vec!["wtw3s"]);
+/// let cells=geohashrust::neighbors_within("wtw3s", 2);
+/// assert_eq!(cells.len(), 25);
+/// assert_eq!(cells[0], geohashrust::ring("wtw3s", 2)[0]);
+/// assert_eq!(cells[12], "wtw3s");
+/// ```
+pub fn neighbors_within(hash: &str, k: u32) -> Vec<String> {
+    let mut row_start = hash.to_string();
+    for _ in 0..k {
+        row_start = neighbor(&row_start, (-1, -1));
+    }
+    let side = (k as usize).saturating_mul(2).saturating_add(1);
+    // A block wider than the world doesn't hold more cells than the world
+    let world = 1usize.checked_shl((hash.len() as u32).saturating_mul(5)).unwrap_or(usize::MAX);
+    let mut seen = HashSet::new();
+    let mut output = Vec::with_capacity(side.saturating_mul(side).min(world));
+    for _ in 0..side {
+        let mut current = row_start.clone();
+        for _ in 0..side {
+            let next = neighbor(&current, (0, 1));
+            if seen.insert(current.clone()) {
+                output.push(current);
+            }
+            current = next;
+        }
+        row_start = neighbor(&row_start, (1, 0));
+    }
+    output
+}
+
+/// An iterator over the cells around a GeoHash, ring after ring
+pub struct Spiral {
+    center: String,
+    ring: u32,
+    cells: ::std::vec::IntoIter<String>,
+}
+
+impl Spiral {
+    /// Get the ring of the cells currently being yielded
+    pub fn ring(&self) -> u32 {
+        self.ring
+    }
+}
+
+impl Iterator for Spiral {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(h) = self.cells.next() {
+                return Some(h);
+            }
+            self.ring += 1;
+            self.cells = ring(&self.center, self.ring).into_iter();
+        }
+    }
+}
+
+/// Iterate over the cells around a GeoHash, starting with the GeoHash itself and
+/// going outwards ring by ring
+///
+/// The iterator never ends, use `take` or `take_while` to bound it.
+///
+/// # Example
+///
+/// ```
+/// let cells: Vec<String>=geohashrust::spiral("wtw3s").take(9).collect();
+/// assert_eq!(cells[0], "wtw3s");
+/// let mut ns=geohashrust::neighbors("wtw3s");
+/// let mut cells=cells.clone();
+/// ns.sort();
+/// cells.sort();
+/// assert_eq!(ns, cells);
+/// ```
+pub fn spiral(hash: &str) -> Spiral {
+    Spiral {
+        center: hash.to_string(),
+        ring: 0,
+        cells: ring(hash, 0).into_iter(),
+    }
 }
 
 
@@ -0,0 +1,75 @@
+use geolocation::GeoLocation;
+use boundingbox::BoundingBox;
+use error::GeohashError;
+
+// Digits of Geohash-36, laid out row by row over a 6x6 grid with the northwest cell
+// first, upper and lowercase letters are different digits
+static GEOHASH36_DIGITS: &str = "23456789bBCdDFgGhHjJKlLMnNPqQrRtTVWX";
+static GEOHASH36_GRID: usize = 6;
+
+// Cell of `bbox` in `row`, counted from the north, and `col`, counted from the west
+fn grid_cell(bbox: &BoundingBox, row: usize, col: usize) -> BoundingBox {
+    let lat_size = bbox.latitude_range() / GEOHASH36_GRID as f64;
+    let lon_size = bbox.longitude_range() / GEOHASH36_GRID as f64;
+    let min_lat = bbox.min_lat + (GEOHASH36_GRID - 1 - row) as f64 * lat_size;
+    let min_lon = bbox.min_lon + col as f64 * lon_size;
+    BoundingBox {
+        min_lat,
+        max_lat: min_lat + lat_size,
+        min_lon,
+        max_lon: min_lon + lon_size,
+    }
+}
+
+/// Encode a `GeoLocation` into Geohash-36 with given precision
+///
+/// Geohash-36 splits each cell into a 6x6 grid rather than halving it bit by bit, so
+/// every character narrows both the latitude and the longitude down 6 times. Cells
+/// include their southern and western borders, the north pole and the antimeridian
+/// at 180 go into the northernmost and easternmost cells.
+///
+/// # Example
+///
+/// ```
+/// let l=geohashrust::GeoLocation::from_coordinates(51.504444, -0.086666);
+/// assert_eq!(geohashrust::encode36(&l, 10), "bdrdC26BqH");
+/// ```
+pub fn encode36(l: &GeoLocation, precision: u8) -> String {
+    let digits = GEOHASH36_DIGITS.as_bytes();
+    let last = GEOHASH36_GRID - 1;
+    let mut bbox = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
+    let mut output = String::with_capacity(precision as usize);
+    for _ in 0..precision {
+        let row = ((l.latitude - bbox.min_lat) / bbox.latitude_range() * GEOHASH36_GRID as f64) as usize;
+        let col = ((l.longitude - bbox.min_lon) / bbox.longitude_range() * GEOHASH36_GRID as f64) as usize;
+        let (row, col) = (last - row.min(last), col.min(last));
+        output.push(digits[row * GEOHASH36_GRID + col] as char);
+        bbox = grid_cell(&bbox, row, col);
+    }
+    output
+}
+
+/// Decode a Geohash-36 into a `BoundingBox`
+///
+/// # Panics
+///
+/// Panics if the hash contains a character outside of the Geohash-36 alphabet, which
+/// is case-sensitive.
+///
+/// # Example
+///
+/// ```
+/// let bbox=geohashrust::decode36("bdrdC26BqH");
+/// assert!(bbox.contains(&geohashrust::GeoLocation::from_coordinates(51.504444, -0.086666)));
+/// ```
+pub fn decode36(hash: &str) -> BoundingBox {
+    let mut bbox = BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0);
+    for c in hash.chars() {
+        let index = match GEOHASH36_DIGITS.find(c) {
+            Some(i) => i,
+            None => panic!("{}", GeohashError::InvalidCharacter(c)),
+        };
+        bbox = grid_cell(&bbox, index / GEOHASH36_GRID, index % GEOHASH36_GRID);
+    }
+    bbox
+}
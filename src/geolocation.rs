@@ -1,7 +1,16 @@
+use std::f64::consts::PI;
+use std::fmt;
 use std::ops::Sub;
+use std::str::FromStr;
 
-// The Earth's radius in kilometers.
-static EARTH_RADIUS: f64 = 6371.009;
+use boundingbox::BoundingBox;
+use error::{GeohashError, CoordinateError};
+
+/// The Earth's mean radius in kilometers.
+pub static EARTH_RADIUS: f64 = 6371.009;
+
+// Length of a statute mile in kilometers
+static KM_PER_MILE: f64 = 1.609344;
 
 /// A geographic location.
 #[derive(Default, Clone, Copy, PartialEq)]
@@ -43,12 +52,76 @@ impl GeoLocation {
         assert!(latitude.abs()<=90.0);
         assert!(longitude.abs()<=180.0);
         GeoLocation {
-            latitude: latitude,
-            longitude: longitude
+            latitude,
+            longitude
         }
     }
 
-    /// Returns the distance between `self` and `other` in meters. The
+    /// Creates a new `GeoLocation` with `latitude` and `longitude`, failing
+    /// if either is out of range instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::{CoordinateError, GeoLocation};
+    /// let l = GeoLocation::try_from_coordinates(48.1333, 11.5667).unwrap();
+    /// assert_eq!(l.latitude, 48.1333);
+    /// assert_eq!(GeoLocation::try_from_coordinates(91.0, 11.5667).err(),
+    ///            Some(CoordinateError::InvalidLatitude(91.0)));
+    /// ```
+    pub fn try_from_coordinates(latitude: f64, longitude: f64) -> Result<GeoLocation, CoordinateError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(CoordinateError::InvalidLatitude(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(CoordinateError::InvalidLongitude(longitude));
+        }
+        Ok(GeoLocation {
+            latitude,
+            longitude
+        })
+    }
+
+    /// Creates a new `GeoLocation` with latitude and longitude given in
+    /// radians, failing if they are out of range once converted to degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = geohashrust::GeoLocation::from_radians(0.5, 1.0).unwrap();
+    /// assert!((l.latitude_rad() - 0.5).abs() < 1e-12);
+    /// assert!((l.longitude_rad() - 1.0).abs() < 1e-12);
+    /// assert!(geohashrust::GeoLocation::from_radians(2.0, 1.0).is_err());
+    /// ```
+    pub fn from_radians(latitude: f64, longitude: f64) -> Result<GeoLocation, GeohashError> {
+        Ok(GeoLocation::try_from_coordinates(latitude.to_degrees(), longitude.to_degrees())?)
+    }
+
+    /// Returns the latitude in radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = geohashrust::GeoLocation::from_coordinates(90.0, 0.0);
+    /// assert_eq!(l.latitude_rad(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn latitude_rad(&self) -> f64 {
+        self.latitude.to_radians()
+    }
+
+    /// Returns the longitude in radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = geohashrust::GeoLocation::from_coordinates(0.0, -180.0);
+    /// assert_eq!(l.longitude_rad(), -std::f64::consts::PI);
+    /// ```
+    pub fn longitude_rad(&self) -> f64 {
+        self.longitude.to_radians()
+    }
+
+    /// Returns the distance between `self` and `other` in kilometers. The
     /// calculation is done using the Haversine formula.
     ///
     /// # Example
@@ -59,6 +132,92 @@ impl GeoLocation {
     /// assert_eq!(new_york.distance_to(&helsinki).round(), 6618.0);
     /// ```
     pub fn distance_to(&self, other: &GeoLocation) -> f64 {
+        EARTH_RADIUS * self.angular_distance_to(other)
+    }
+
+    /// Returns the distance between `self` and `other` in meters, see
+    /// `distance_to`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let new_york = geohashrust::GeoLocation::from_coordinates(40.7127, -74.0059);
+    /// let helsinki = geohashrust::GeoLocation::from_coordinates(60.1708, 24.9375);
+    /// assert_eq!(new_york.distance_to_meters(&helsinki).round(), 6618347.0);
+    /// ```
+    pub fn distance_to_meters(&self, other: &GeoLocation) -> f64 {
+        self.distance_to(other) * 1000.0
+    }
+
+    /// Returns the distance between `self` and `other` in statute miles,
+    /// see `distance_to`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let new_york = geohashrust::GeoLocation::from_coordinates(40.7127, -74.0059);
+    /// let helsinki = geohashrust::GeoLocation::from_coordinates(60.1708, 24.9375);
+    /// assert_eq!(new_york.distance_to_miles(&helsinki).round(), 4112.0);
+    /// ```
+    pub fn distance_to_miles(&self, other: &GeoLocation) -> f64 {
+        self.distance_to(other) / KM_PER_MILE
+    }
+
+    /// Returns an approximate distance between `self` and `other` in
+    /// kilometers, using the equirectangular projection around their mean
+    /// latitude. It's several times faster than `distance_to`, and close to
+    /// it for nearby points, but only for nearby points: the error grows
+    /// with the distance and towards the poles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(48.1333, 11.5667);
+    /// let b = geohashrust::GeoLocation::from_coordinates(48.1500, 11.6000);
+    /// assert!((a.distance_to_fast(&b) - a.distance_to(&b)).abs() < 1e-3);
+    /// ```
+    pub fn distance_to_fast(&self, other: &GeoLocation) -> f64 {
+        let mut dlon = (other.longitude - self.longitude).to_radians();
+        // Take the shorter way around the antimeridian
+        if dlon.abs() > PI {
+            dlon -= 2.0 * PI * dlon.signum();
+        }
+        let x = dlon * ((self.latitude + other.latitude) / 2.0).to_radians().cos();
+        let y = (other.latitude - self.latitude).to_radians();
+        EARTH_RADIUS * (x * x + y * y).sqrt()
+    }
+
+    /// Returns the distance in kilometers from `self` to the closest point
+    /// of `bbox`, which is 0 if `self` is inside it.
+    ///
+    /// Same as `BoundingBox::distance_to_point`, the closest point is found
+    /// by clamping the latitude and longitude into the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geohashrust::{BoundingBox, GeoLocation};
+    /// let b = BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    /// assert_eq!(GeoLocation::from_coordinates(15.0, 35.0).distance_to_box(&b), 0.0);
+    /// let d = GeoLocation::from_coordinates(21.0, 35.0).distance_to_box(&b);
+    /// assert!((d - GeoLocation::from_coordinates(20.0, 35.0).distance_to(&GeoLocation::from_coordinates(21.0, 35.0))).abs() < 1e-9);
+    /// ```
+    pub fn distance_to_box(&self, bbox: &BoundingBox) -> f64 {
+        bbox.distance_to_point(self)
+    }
+
+    /// Returns the central angle between `self` and `other` in radians,
+    /// i.e. the great-circle distance on a unit sphere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let new_york = geohashrust::GeoLocation::from_coordinates(40.7127, -74.0059);
+    /// let helsinki = geohashrust::GeoLocation::from_coordinates(60.1708, 24.9375);
+    /// let d = new_york.angular_distance_to(&helsinki) * geohashrust::EARTH_RADIUS;
+    /// assert!((d - new_york.distance_to(&helsinki)).abs() < 1e-9);
+    /// ```
+    pub fn angular_distance_to(&self, other: &GeoLocation) -> f64 {
         let lat1 = self.latitude.to_radians();
         let lat2 = other.latitude.to_radians();
         let dlat = (other.latitude - self.latitude).to_radians();
@@ -67,13 +226,292 @@ impl GeoLocation {
         let a = (dlat / 2.0).sin() * (dlat / 2.0).sin() +
             lat1.cos() * lat2.cos() *
             (dlon / 2.0).sin() * (dlon / 2.0).sin();
-        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+        2.0 * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Returns the distance in kilometers between `self` and `other` along
+    /// the rhumb line, i.e. keeping a constant bearing. It's never shorter
+    /// than the great-circle distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let b = geohashrust::GeoLocation::from_coordinates(0.0, 10.0);
+    /// assert!((a.rhumb_distance_to(&b) - a.distance_to(&b)).abs() < 1e-9);
+    /// let c = geohashrust::GeoLocation::from_coordinates(60.0, 10.0);
+    /// let d = geohashrust::GeoLocation::from_coordinates(60.0, 100.0);
+    /// assert!(c.rhumb_distance_to(&d) > c.distance_to(&d));
+    /// ```
+    pub fn rhumb_distance_to(&self, other: &GeoLocation) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let dlat = lat2 - lat1;
+        let mut dlon = (other.longitude - self.longitude).to_radians();
+        // Take the shorter way around the antimeridian
+        if dlon.abs() > PI {
+            dlon -= 2.0 * PI * dlon.signum();
+        }
+
+        // Stretched latitude difference on the Mercator projection
+        let dpsi = ((PI / 4.0 + lat2 / 2.0).tan() / (PI / 4.0 + lat1 / 2.0).tan()).ln();
+        let q = if dpsi.abs() > 1e-12 {dlat / dpsi} else {lat1.cos()};
+        EARTH_RADIUS * (dlat * dlat + q * q * dlon * dlon).sqrt()
+    }
+
+    /// Returns the point at fraction `t` of the way from `self` to `other`
+    /// along the great circle, using spherical linear interpolation. `t=0`
+    /// gives `self` and `t=1` gives `other`. The great circle between
+    /// antipodal points isn't unique, `self` is returned in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let b = geohashrust::GeoLocation::from_coordinates(0.0, 10.0);
+    /// let m = a.interpolate(&b, 0.25);
+    /// assert!(m.latitude.abs() < 1e-9);
+    /// assert!((m.longitude - 2.5).abs() < 1e-9);
+    /// ```
+    pub fn interpolate(&self, other: &GeoLocation, t: f64) -> GeoLocation {
+        if t == 0.0 {
+            return *self;
+        }
+        if t == 1.0 {
+            return *other;
+        }
+        let d = self.angular_distance_to(other);
+        if d.sin().abs() < 1e-12 {
+            return *self;
+        }
+        let a = ((1.0 - t) * d).sin() / d.sin();
+        let b = (t * d).sin() / d.sin();
 
-        EARTH_RADIUS * c
+        let (lat1, lon1) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (lat2, lon2) = (other.latitude.to_radians(), other.longitude.to_radians());
+        let x = a * lat1.cos() * lon1.cos() + b * lat2.cos() * lon2.cos();
+        let y = a * lat1.cos() * lon1.sin() + b * lat2.cos() * lon2.sin();
+        let z = a * lat1.sin() + b * lat2.sin();
+        GeoLocation {
+            latitude: z.atan2((x * x + y * y).sqrt()).to_degrees(),
+            longitude: y.atan2(x).to_degrees(),
+        }
     }
+
+    /// Returns the halfway point between `self` and `other` along the great
+    /// circle, i.e. `interpolate` at `t=0.5`. The midpoint of antipodal
+    /// points isn't unique, `self` is returned in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(10.0, 170.0);
+    /// let b = geohashrust::GeoLocation::from_coordinates(10.0, -170.0);
+    /// let m = a.midpoint(&b);
+    /// assert!((m.longitude.abs() - 180.0).abs() < 1e-9);
+    /// assert!(m.latitude > 10.0);
+    /// ```
+    pub fn midpoint(&self, other: &GeoLocation) -> GeoLocation {
+        self.interpolate(other, 0.5)
+    }
+
+    /// Returns the initial bearing from `self` towards `other` along the
+    /// great circle, in degrees clockwise from north within `[0, 360)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let b = geohashrust::GeoLocation::from_coordinates(0.0, 10.0);
+    /// let c = geohashrust::GeoLocation::from_coordinates(10.0, 0.0);
+    /// assert!((a.bearing_to(&b) - 90.0).abs() < 1e-9);
+    /// assert!(a.bearing_to(&c).abs() < 1e-9);
+    /// ```
+    pub fn bearing_to(&self, other: &GeoLocation) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let dlon = (other.longitude - self.longitude).to_radians();
+
+        let y = dlon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+        (y.atan2(x).to_degrees() + 360.0) % 360.0
+    }
+
+    /// Returns the distance in kilometers between `self` and the great
+    /// circle going through `start` and `end`. The result is positive when
+    /// `self` is on the right of the path and negative on the left.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let start = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let end = geohashrust::GeoLocation::from_coordinates(0.0, 10.0);
+    /// let p = geohashrust::GeoLocation::from_coordinates(1.0, 5.0);
+    /// let d = p.cross_track_distance(&start, &end);
+    /// assert!((d + start.distance_to(&geohashrust::GeoLocation::from_coordinates(1.0, 0.0))).abs() < 1e-6);
+    /// ```
+    pub fn cross_track_distance(&self, start: &GeoLocation, end: &GeoLocation) -> f64 {
+        let d13 = start.angular_distance_to(self);
+        let theta13 = start.bearing_to(self).to_radians();
+        let theta12 = start.bearing_to(end).to_radians();
+        EARTH_RADIUS * (d13.sin() * (theta13 - theta12).sin()).asin()
+    }
+
+    /// Returns the distance in kilometers from `start` to the point on the
+    /// great circle through `start` and `end` closest to `self`. The result
+    /// is negative when that point lies behind `start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let start = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let end = geohashrust::GeoLocation::from_coordinates(0.0, 10.0);
+    /// let p = geohashrust::GeoLocation::from_coordinates(1.0, 5.0);
+    /// let d = p.along_track_distance(&start, &end);
+    /// assert!((d - start.distance_to(&geohashrust::GeoLocation::from_coordinates(0.0, 5.0))).abs() < 1e-6);
+    /// ```
+    pub fn along_track_distance(&self, start: &GeoLocation, end: &GeoLocation) -> f64 {
+        let d13 = start.angular_distance_to(self);
+        let dtheta = (start.bearing_to(self) - start.bearing_to(end)).to_radians();
+        let dxt = (d13.sin() * dtheta.sin()).asin();
+        let dat = (d13.cos() / dxt.cos()).clamp(-1.0, 1.0).acos();
+        if dtheta.cos() < 0.0 {
+            -EARTH_RADIUS * dat
+        } else {
+            EARTH_RADIUS * dat
+        }
+    }
+
+    /// Returns the point reached by travelling `distance_km` kilometers
+    /// from `self` along the great circle with initial bearing `bearing_deg`.
+    ///
+    /// The longitude of the result is normalized into `[-180, 180]`. Paths
+    /// going over a pole carry on down the other side, so they come out
+    /// heading the opposite way on the far meridian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(0.0, 0.0);
+    /// let b = a.destination(90.0, a.distance_to(&geohashrust::GeoLocation::from_coordinates(0.0, 10.0)));
+    /// assert!(b.latitude.abs() < 1e-9);
+    /// assert!((b.longitude - 10.0).abs() < 1e-9);
+    /// ```
+    pub fn destination(&self, bearing_deg: f64, distance_km: f64) -> GeoLocation {
+        let lat1 = self.latitude.to_radians();
+        let lon1 = self.longitude.to_radians();
+        let bearing = bearing_deg.to_radians();
+        let d = distance_km / EARTH_RADIUS;
+
+        let lat2 = (lat1.sin() * d.cos() + lat1.cos() * d.sin() * bearing.cos()).clamp(-1.0, 1.0).asin();
+        let lon2 = lon1 + (bearing.sin() * d.sin() * lat1.cos()).atan2(d.cos() - lat1.sin() * lat2.sin());
+
+        let mut longitude = (lon2.to_degrees() + 540.0) % 360.0 - 180.0;
+        if longitude == -180.0 && lon2 > 0.0 {
+            longitude = 180.0;
+        }
+        GeoLocation {
+            latitude: lat2.to_degrees(),
+            longitude
+        }
+    }
+
+    /// Returns `self` brought back into range, with the longitude wrapped
+    /// into `[-180, 180]` and the latitude clamped to `[-90, 90]`.
+    ///
+    /// Longitude is periodic, so `181` is the same meridian as `-179` and
+    /// wrapping keeps the location where it was. Latitude isn't, a latitude
+    /// past a pole is most likely a bad reading and is pinned to the pole
+    /// rather than reflected onto the other side. Coordinates already in
+    /// range are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = geohashrust::GeoLocation { latitude: 95.0, longitude: -185.0 }.normalize();
+    /// assert_eq!(l.latitude, 90.0);
+    /// assert_eq!(l.longitude, 175.0);
+    /// ```
+    pub fn normalize(&self) -> GeoLocation {
+        let longitude = if (-180.0..=180.0).contains(&self.longitude) {
+            self.longitude
+        } else {
+            (self.longitude + 180.0).rem_euclid(360.0) - 180.0
+        };
+        GeoLocation {
+            latitude: self.latitude.clamp(-90.0, 90.0),
+            longitude
+        }
+    }
+
+    /// Returns `self` rounded to `decimals` decimal places as a
+    /// `QuantizedLocation`, which implements `Eq` and `Hash` and so can key
+    /// a `HashMap` or `HashSet`.
+    ///
+    /// Both coordinates are scaled by `10^decimals` and rounded to the
+    /// nearest integer, with halfway cases rounded away from zero. Locations
+    /// rounding to the same integers are equal. Note that `-0.00001` and
+    /// `0.00001` both round to `0` at 4 decimals, while two locations close
+    /// to each other may still fall on either side of a rounding boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimals` is greater than 15.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = geohashrust::GeoLocation::from_coordinates(48.13331, 11.56669);
+    /// let b = geohashrust::GeoLocation::from_coordinates(48.13329, 11.56672);
+    /// assert!(a.quantized(4) == b.quantized(4));
+    /// assert!(a.quantized(5) != b.quantized(5));
+    /// ```
+    pub fn quantized(&self, decimals: u8) -> QuantizedLocation {
+        assert!(decimals <= 15, "Too many decimals {}", decimals);
+        let scale = 10f64.powi(decimals as i32);
+        QuantizedLocation {
+            latitude: (self.latitude * scale).round() as i64,
+            longitude: (self.longitude * scale).round() as i64,
+            decimals
+        }
+    }
+}
+
+/// A geographic location rounded to a fixed number of decimal places,
+/// created with `GeoLocation::quantized`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct QuantizedLocation {
+    latitude: i64,
+    longitude: i64,
+    decimals: u8
 }
 
-/// Returns the distance between `self` and `other` in meters. The
+impl QuantizedLocation {
+    /// Returns the number of decimal places kept.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the rounded location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = geohashrust::GeoLocation::from_coordinates(48.13331, 11.56669);
+    /// let q = l.quantized(2).location();
+    /// assert_eq!(q.latitude, 48.13);
+    /// assert_eq!(q.longitude, 11.57);
+    /// ```
+    pub fn location(&self) -> GeoLocation {
+        let scale = 10f64.powi(self.decimals as i32);
+        GeoLocation {
+            latitude: self.latitude as f64 / scale,
+            longitude: self.longitude as f64 / scale
+        }
+    }
+}
+
+/// Returns the distance between `self` and `other` in kilometers. The
 /// calculation is done using the Haversine formula.
 ///
 /// # Example
@@ -90,15 +528,49 @@ impl Sub<GeoLocation> for GeoLocation {
     }
 }
 
+/// Formats the location as `(latitude, longitude)`. The precision of the
+/// format, if any, is the number of decimal places of both coordinates.
+///
+/// # Example
+///
+/// ```
+/// let l = geohashrust::GeoLocation::from_coordinates(31.23, 121.473);
+/// assert_eq!(l.to_string(), "(31.23, 121.473)");
+/// assert_eq!(format!("{:.2}", l), "(31.23, 121.47)");
+/// ```
+impl fmt::Display for GeoLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*})", p, self.latitude, p, self.longitude),
+            None => write!(f, "({}, {})", self.latitude, self.longitude),
+        }
+    }
+}
 
+/// Parses a location from `latitude,longitude` in degrees, with optional
+/// whitespace around each coordinate.
+///
+/// # Example
+///
+/// ```
+/// let l: geohashrust::GeoLocation = " 48.1333, 11.5667 ".parse().unwrap();
+/// assert_eq!(l.latitude, 48.1333);
+/// assert_eq!(l.longitude, 11.5667);
+/// assert!("48.1333".parse::<geohashrust::GeoLocation>().is_err());
+/// assert!("98.1333,11.5667".parse::<geohashrust::GeoLocation>().is_err());
+/// ```
+impl FromStr for GeoLocation {
+    type Err = GeohashError;
 
-
-
-
-
-
-
-
-
-
-
+    fn from_str(s: &str) -> Result<GeoLocation, GeohashError> {
+        let mut parts = s.split(',');
+        let (latitude, longitude) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(lat), Some(lon), None) => (lat.trim().parse::<f64>(), lon.trim().parse::<f64>()),
+            _ => return Err(GeohashError::MalformedData),
+        };
+        match (latitude, longitude) {
+            (Ok(lat), Ok(lon)) => Ok(GeoLocation::try_from_coordinates(lat, lon)?),
+            _ => Err(GeohashError::MalformedData),
+        }
+    }
+}
@@ -1,10 +1,53 @@
 //#![feature(std_misc)]
 //#![feature(core)]
+// The replacements clippy suggests, is_multiple_of, div_ceil and iter::repeat_n, need
+// Rust 1.87, 1.73 and 1.82
+#![allow(clippy::manual_is_multiple_of, clippy::manual_div_ceil, clippy::manual_repeat_n)]
 
-pub use geolocation::GeoLocation;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+pub use geolocation::{GeoLocation, QuantizedLocation, EARTH_RADIUS};
 pub use boundingbox::BoundingBox;
-pub use geohash::{BinaryHash, encode, decode, neighbor, neighbors};
+pub use error::{GeohashError, CoordinateError};
+pub use geohash::{Direction, BinaryHash, BitIter, Encoder, Spiral, NeighborIter, encode,
+                  encode_coords, encode_into, encode_upper, encode_with_case, encode_within,
+                  encode_fast, is_valid_geohash, decode, decode_full, decode_to_location,
+                  decode_with_error, try_decode, decode_case_insensitive, geohash_contains,
+                  is_within_cell, decode_many, neighbor, neighbors, neighbor_iter,
+                  neighbors_clockwise, edge_neighbors, neighbors_in_box, neighbors_within,
+                  are_adjacent, ring, spiral, is_edge_cell, cell_is_entirely,
+                  precision_for_cell_size, geohash_error_meters, precision_for_zoom,
+                  zoom_for_precision, cell_dimensions, cells_per_km2, geohash_to_key,
+                  key_to_geohash, relative_geohash, reconstruct_geohash, common_prefix,
+                  common_prefix_all, geohash_range, parent, parent_corners, geohash_children};
+pub use utm::UtmCoordinate;
+pub use cover::{cover_bounding_box, geohashes_in_box, cover_circle, cells_inside_circle, coverage_efficiency,
+                circle_coverage_ratio, covering_diff, serialize_covering, deserialize_covering,
+                covering_centroid, cover_symmetric_difference, find_overlaps, covering_jaccard};
+pub use search::{nearest_cell_excluding, unique_geohash, min_distinguishing_precision,
+                 geohash_distance_to_point, precision_histogram, smallest_enclosing_circle,
+                 flood_fill};
+pub use path::{are_collinear, simplify_path, closest_point_on_segment, path_length, path_length_rhumb,
+               point_at_distance};
+pub use pluscode::{geohash_to_plus_code, plus_code_to_geohash, decode_plus_code};
+pub use geohash36::{encode36, decode36};
+#[cfg(feature = "rand")]
+pub use fuzz::fuzz_location;
 
+mod error;
 mod geolocation;
 mod boundingbox;
-mod geohash;
\ No newline at end of file
+mod geohash;
+mod utm;
+mod cover;
+mod search;
+mod path;
+mod pluscode;
+mod geohash36;
+#[cfg(feature = "rand")]
+mod fuzz;
+#[cfg(feature = "serde")]
+mod serde_support;
@@ -0,0 +1,172 @@
+use geolocation::GeoLocation;
+
+/// Test if `b` lies on the great circle through `a` and `c`, within `tolerance_m` meters
+///
+/// When `a` and `c` coincide there is no single great circle, `b` then has to be
+/// within the tolerance of `a`.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let a=GeoLocation::from_coordinates(10.0, 20.0);
+/// let b=GeoLocation::from_coordinates(20.0, 20.0);
+/// let c=GeoLocation::from_coordinates(30.0, 20.0);
+/// assert!(geohashrust::are_collinear(&a, &b, &c, 1.0));
+/// assert!(!geohashrust::are_collinear(&a, &GeoLocation::from_coordinates(20.0, 20.1), &c, 1.0));
+/// ```
+pub fn are_collinear(a: &GeoLocation, b: &GeoLocation, c: &GeoLocation, tolerance_m: f64) -> bool {
+    deviation(b, a, c) <= tolerance_m
+}
+
+/// Get the length in kilometers of a path along great circles between the points
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let a=GeoLocation::from_coordinates(0.0, 0.0);
+/// let b=GeoLocation::from_coordinates(0.0, 1.0);
+/// assert!((geohashrust::path_length(&[a, b, a])-2.0*a.distance_to(&b)).abs() < 1e-9);
+/// assert_eq!(geohashrust::path_length(&[a]), 0.0);
+/// ```
+pub fn path_length(points: &[GeoLocation]) -> f64 {
+    points.windows(2).map(|w| w[0].distance_to(&w[1])).sum()
+}
+
+/// Get the length in kilometers of a path along rhumb lines between the points
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let a=GeoLocation::from_coordinates(50.0, 0.0);
+/// let b=GeoLocation::from_coordinates(50.0, 30.0);
+/// assert!(geohashrust::path_length_rhumb(&[a, b]) > geohashrust::path_length(&[a, b]));
+/// ```
+pub fn path_length_rhumb(points: &[GeoLocation]) -> f64 {
+    points.windows(2).map(|w| w[0].rhumb_distance_to(&w[1])).sum()
+}
+
+/// Get the point `distance_m` meters along a path, following great circles between
+/// the points
+///
+/// Returns `None` if the distance is negative or longer than the path.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let a=GeoLocation::from_coordinates(0.0, 0.0);
+/// let b=GeoLocation::from_coordinates(0.0, 1.0);
+/// let half=a.distance_to(&b)*500.0;
+/// let p=geohashrust::point_at_distance(&[a, b], half).unwrap();
+/// assert!((p.longitude-0.5).abs() < 1e-9);
+/// assert!(geohashrust::point_at_distance(&[a, b], 3.0*half).is_none());
+/// ```
+pub fn point_at_distance(points: &[GeoLocation], distance_m: f64) -> Option<GeoLocation> {
+    if distance_m < 0.0 {
+        return None;
+    }
+    let mut remaining = distance_m / 1000.0;
+    for w in points.windows(2) {
+        let length = w[0].distance_to(&w[1]);
+        if remaining <= length {
+            let t = if length > 0.0 {remaining / length} else {0.0};
+            return Some(w[0].interpolate(&w[1], t));
+        }
+        remaining -= length;
+    }
+    // Allow for rounding errors when asking for the end of the path
+    if remaining <= 1e-9 {
+        return points.last().cloned();
+    }
+    None
+}
+
+// Get how far a point strays from the great circle through `start` and `end`, in meters
+fn deviation(point: &GeoLocation, start: &GeoLocation, end: &GeoLocation) -> f64 {
+    if start == end {
+        point.distance_to(start) * 1000.0
+    } else {
+        point.cross_track_distance(start, end).abs() * 1000.0
+    }
+}
+
+/// Simplify a path with the Ramer-Douglas-Peucker algorithm
+///
+/// Points deviating at most `tolerance_m` meters from the great circle through the
+/// kept points around them are dropped. The end points are always kept.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let path=vec![
+///     GeoLocation::from_coordinates(0.0, 0.0),
+///     GeoLocation::from_coordinates(0.0, 1.0),
+///     GeoLocation::from_coordinates(0.0, 2.0),
+///     GeoLocation::from_coordinates(1.0, 2.0),
+/// ];
+/// let simplified=geohashrust::simplify_path(&path, 10.0);
+/// assert_eq!(simplified.len(), 3);
+/// assert!(simplified[1]==path[2]);
+/// ```
+pub fn simplify_path(points: &[GeoLocation], tolerance_m: f64) -> Vec<GeoLocation> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let mut farthest = None;
+        let mut max_deviation = tolerance_m;
+        for i in first+1..last {
+            let d = deviation(&points[i], &points[first], &points[last]);
+            if d > max_deviation {
+                max_deviation = d;
+                farthest = Some(i);
+            }
+        }
+        if let Some(i) = farthest {
+            keep[i] = true;
+            ranges.push((first, i));
+            ranges.push((i, last));
+        }
+    }
+
+    points.iter().zip(keep.iter())
+        .filter(|&(_, &k)| k)
+        .map(|(p, _)| *p)
+        .collect()
+}
+
+/// Get the point of the great-circle segment from `start` to `end` closest to `point`
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let start=GeoLocation::from_coordinates(0.0, 0.0);
+/// let end=GeoLocation::from_coordinates(0.0, 10.0);
+/// let p=geohashrust::closest_point_on_segment(&GeoLocation::from_coordinates(1.0, 5.0), &start, &end);
+/// assert!(p.latitude.abs() < 1e-9);
+/// assert!((p.longitude-5.0).abs() < 1e-9);
+/// let p=geohashrust::closest_point_on_segment(&GeoLocation::from_coordinates(1.0, -5.0), &start, &end);
+/// assert!(p==start);
+/// ```
+pub fn closest_point_on_segment(point: &GeoLocation, start: &GeoLocation, end: &GeoLocation) -> GeoLocation {
+    let length = start.distance_to(end);
+    let along = point.along_track_distance(start, end);
+    if start == end || along <= 0.0 {
+        *start
+    } else if along >= length {
+        *end
+    } else {
+        start.destination(start.bearing_to(end), along)
+    }
+}
@@ -0,0 +1,156 @@
+use geolocation::GeoLocation;
+use boundingbox::BoundingBox;
+use error::GeohashError;
+use geohash::{encode, decode};
+
+// Digits of Open Location Codes, in order of their values
+static PLUS_CODE_DIGITS: &str = "23456789CFGHJMPQRVWX";
+static PLUS_CODE_SEPARATOR: char = '+';
+static PLUS_CODE_PADDING: char = '0';
+// Number of digits before the separator
+static PLUS_CODE_SEPARATOR_POSITION: usize = 8;
+// Number of digits encoded as latitude/longitude pairs, the rest refine a 5x4 grid
+static PLUS_CODE_PAIR_LENGTH: usize = 10;
+static PLUS_CODE_MAX_LENGTH: usize = 15;
+// Number of steps per degree with the longest code
+static PLUS_CODE_LAT_STEPS: f64 = 8000.0 * 3125.0;
+static PLUS_CODE_LON_STEPS: f64 = 8000.0 * 1024.0;
+
+// Encode a location as a full Open Location Code with `code_length` digits
+fn encode_plus_code(l: &GeoLocation, code_length: usize) -> String {
+    let digits = PLUS_CODE_DIGITS.as_bytes();
+    let lat_max = (180.0 * PLUS_CODE_LAT_STEPS) as i64;
+    let lon_max = (360.0 * PLUS_CODE_LON_STEPS) as i64;
+    // The north pole goes into the topmost cell
+    let mut lat = (((l.latitude + 90.0) * PLUS_CODE_LAT_STEPS).floor() as i64).clamp(0, lat_max - 1);
+    let mut lon = (((l.longitude + 180.0) * PLUS_CODE_LON_STEPS).floor() as i64).rem_euclid(lon_max);
+
+    // Digits are produced from the least significant one
+    let mut code = Vec::with_capacity(PLUS_CODE_MAX_LENGTH);
+    for _ in PLUS_CODE_PAIR_LENGTH..PLUS_CODE_MAX_LENGTH {
+        code.push(digits[(lat % 5 * 4 + lon % 4) as usize]);
+        lat /= 5;
+        lon /= 4;
+    }
+    for _ in 0..PLUS_CODE_PAIR_LENGTH / 2 {
+        code.push(digits[(lon % 20) as usize]);
+        code.push(digits[(lat % 20) as usize]);
+        lat /= 20;
+        lon /= 20;
+    }
+    code.reverse();
+    code.truncate(code_length);
+
+    let mut output: String = code.into_iter().map(|d| d as char).collect();
+    while output.len() < PLUS_CODE_SEPARATOR_POSITION {
+        output.push(PLUS_CODE_PADDING);
+    }
+    output.insert(PLUS_CODE_SEPARATOR_POSITION, PLUS_CODE_SEPARATOR);
+    output
+}
+
+/// Decode a full Open Location Code into the `BoundingBox` of its cell
+///
+/// Codes are case-insensitive and may be padded with `'0'`. Short codes, which need a
+/// reference location to recover the dropped leading digits, aren't supported.
+///
+/// # Example
+///
+/// ```
+/// let b=geohashrust::decode_plus_code("8FVC9G8F+6X").unwrap();
+/// assert!(b.contains(&geohashrust::GeoLocation::from_coordinates(47.365590, 8.524997)));
+/// assert!(geohashrust::decode_plus_code("8FVC+").is_err());
+/// ```
+pub fn decode_plus_code(code: &str) -> Result<BoundingBox, GeohashError> {
+    if code.is_empty() {
+        return Err(GeohashError::EmptyHash);
+    }
+    if code.find(PLUS_CODE_SEPARATOR) != Some(PLUS_CODE_SEPARATOR_POSITION) || code.len() > PLUS_CODE_MAX_LENGTH + 1 {
+        return Err(GeohashError::MalformedData);
+    }
+
+    let mut values = Vec::with_capacity(PLUS_CODE_MAX_LENGTH);
+    let mut padded = false;
+    for (i, c) in code.chars().enumerate() {
+        if i == PLUS_CODE_SEPARATOR_POSITION {
+            continue;
+        }
+        if c == PLUS_CODE_PADDING {
+            padded = true;
+            continue;
+        }
+        match PLUS_CODE_DIGITS.find(c.to_ascii_uppercase()) {
+            // Padding only goes at the end, before the separator
+            Some(_) if padded => return Err(GeohashError::MalformedData),
+            Some(v) => values.push(v as f64),
+            None => return Err(GeohashError::InvalidCharacter(c)),
+        }
+    }
+    // Pairs can't be split, and a single digit can't follow the separator
+    if values.len() < 2 || (values.len() <= PLUS_CODE_PAIR_LENGTH && values.len() % 2 == 1)
+        || (padded && code.len() > PLUS_CODE_SEPARATOR_POSITION + 1) {
+        return Err(GeohashError::MalformedData);
+    }
+    // The first latitude digit only goes up to 180 degrees
+    if values[0] >= 9.0 || values[1] >= 18.0 {
+        return Err(GeohashError::MalformedData);
+    }
+
+    let (mut lat, mut lon) = (-90.0, -180.0);
+    let (mut lat_size, mut lon_size) = (400.0, 400.0);
+    for pair in values.iter().take(PLUS_CODE_PAIR_LENGTH).collect::<Vec<_>>().chunks(2) {
+        lat_size /= 20.0;
+        lon_size /= 20.0;
+        lat += pair[0] * lat_size;
+        lon += pair[1] * lon_size;
+    }
+    for v in values.iter().skip(PLUS_CODE_PAIR_LENGTH) {
+        lat_size /= 5.0;
+        lon_size /= 4.0;
+        lat += (v / 4.0).floor() * lat_size;
+        lon += (v % 4.0) * lon_size;
+    }
+    Ok(BoundingBox {
+        min_lat: lat,
+        max_lat: lat + lat_size,
+        min_lon: lon,
+        max_lon: lon + lon_size,
+    })
+}
+
+/// Convert a GeoHash into an Open Location Code, aka Plus Code, with `code_length` digits
+///
+/// The conversion goes through the center of the GeoHash cell. The two grids don't
+/// line up, so the Plus Code cell contains the center but not necessarily the whole
+/// GeoHash cell, and converting back may give a different GeoHash.
+///
+/// # Panics
+///
+/// Panics if `code_length` isn't 2, 4, 6, 8 or between 10 and 15.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::geohash_to_plus_code("u0qj8", 10), "8FVC9FW5+2Q");
+/// assert_eq!(geohashrust::geohash_to_plus_code("u0qj8", 4), "8FVC0000+");
+/// ```
+pub fn geohash_to_plus_code(hash: &str, code_length: usize) -> String {
+    assert!((2..=PLUS_CODE_MAX_LENGTH).contains(&code_length)
+        && (code_length >= PLUS_CODE_PAIR_LENGTH || code_length % 2 == 0),
+        "Invalid Plus Code length {}", code_length);
+    encode_plus_code(&decode(hash).center(), code_length)
+}
+
+/// Convert an Open Location Code, aka Plus Code, into a GeoHash with given precision
+///
+/// The conversion goes through the center of the Plus Code cell, see
+/// `geohash_to_plus_code` for the caveats.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(geohashrust::plus_code_to_geohash("8FVC9G8F+6X", 7).unwrap(), "u0qj3yx");
+/// ```
+pub fn plus_code_to_geohash(code: &str, precision: u8) -> Result<String, GeohashError> {
+    Ok(encode(&decode_plus_code(code)?.center(), precision))
+}
@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use geolocation::{GeoLocation, EARTH_RADIUS};
+use geohash::{encode, decode, neighbors, ring};
+
+// How many rings around the starting cell `nearest_cell_excluding` looks at
+static NEAREST_CELL_MAX_RINGS: u32 = 16;
+
+/// Find the cell with given precision closest to a point that isn't in `exclude`
+///
+/// The rings around the cell containing the point are searched outwards, within a
+/// ring the cell whose center is closest to the point wins. Gives up with `None`
+/// after 16 rings.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// let p=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// let mut exclude=HashSet::new();
+/// assert_eq!(geohashrust::nearest_cell_excluding(&p, 5, &exclude), Some("wtw3r".to_string()));
+/// exclude.insert("wtw3r".to_string());
+/// let h=geohashrust::nearest_cell_excluding(&p, 5, &exclude).unwrap();
+/// assert!(geohashrust::neighbors("wtw3r").contains(&h));
+/// ```
+pub fn nearest_cell_excluding(point: &GeoLocation, precision: u8, exclude: &HashSet<String>) -> Option<String> {
+    let start = encode(point, precision);
+    for k in 0..=NEAREST_CELL_MAX_RINGS {
+        let nearest = ring(&start, k).into_iter()
+            .filter(|h| !exclude.contains(h))
+            .map(|h| {
+                let d = point.distance_to(&decode(&h).center());
+                (h, d)
+            })
+            .fold(None, |best: Option<(String, f64)>, (h, d)| match best {
+                Some((bh, bd)) if bd <= d => Some((bh, bd)),
+                _ => Some((h, d)),
+            });
+        if let Some((h, _)) = nearest {
+            return Some(h);
+        }
+    }
+    None
+}
+
+/// Get the shortest GeoHash of a point that none of the `others` share
+///
+/// If some other point falls into the same cell even at `max_precision`, the GeoHash
+/// with `max_precision` is returned anyway. With no other points, or none sharing even
+/// the first character, the GeoHash has a single character.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let p=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// let others=[GeoLocation::from_coordinates(31.55, 121.46), GeoLocation::from_coordinates(-33.87, 151.21)];
+/// assert_eq!(geohashrust::unique_geohash(&p, &others, 12), "wtw3");
+/// assert_eq!(geohashrust::unique_geohash(&p, &[], 12), "w");
+/// ```
+pub fn unique_geohash(point: &GeoLocation, others: &[GeoLocation], max_precision: u8) -> String {
+    let mut hash = encode(point, max_precision);
+    let shared = others.iter()
+        .map(|o| {
+            let other = encode(o, max_precision);
+            hash.bytes().zip(other.bytes()).take_while(|&(a, b)| a == b).count()
+        })
+        .max();
+    let len = (shared.unwrap_or(0) + 1).min(hash.len());
+    hash.truncate(len);
+    hash
+}
+
+/// Get the shortest precision at which all points have distinct GeoHashes, or `None`
+/// if some of them still share a cell at `max_precision`
+///
+/// Any precision tells a single point apart, so `Some(0)` is returned for less than
+/// two points.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let points=[GeoLocation::from_coordinates(31.16373922, 121.62585927),
+///             GeoLocation::from_coordinates(31.55, 121.46)];
+/// assert_eq!(geohashrust::min_distinguishing_precision(&points, 12), Some(4));
+/// assert_eq!(geohashrust::min_distinguishing_precision(&points, 3), None);
+/// ```
+pub fn min_distinguishing_precision(points: &[GeoLocation], max_precision: u8) -> Option<u8> {
+    let mut hashes: Vec<String> = points.iter().map(|p| encode(p, max_precision)).collect();
+    hashes.sort();
+    // Sorted GeoHashes share the longest prefixes with their neighbors in the list
+    let mut precision = 0;
+    for pair in hashes.windows(2) {
+        if pair[0] == pair[1] {
+            return None;
+        }
+        let shared = pair[0].bytes().zip(pair[1].bytes()).take_while(|&(a, b)| a == b).count();
+        precision = precision.max(shared + 1);
+    }
+    Some(precision as u8)
+}
+
+/// Collect the cells connected to `start` whose GeoHashes pass a predicate
+///
+/// The cells are visited breadth-first across all 8 neighbors, and returned in the
+/// order they are visited. The search stops once `max_cells` cells are found. Nothing
+/// is returned if `start` itself doesn't pass.
+///
+/// # Example
+///
+/// ```
+/// let region=geohashrust::flood_fill("wtw3s", 100, |h| h.starts_with("wtw3"));
+/// assert_eq!(region.len(), 32);
+/// assert_eq!(region[0], "wtw3s");
+/// ```
+pub fn flood_fill<F: Fn(&str) -> bool>(start: &str, max_cells: usize, predicate: F) -> Vec<String> {
+    let mut output = Vec::new();
+    if max_cells == 0 || !predicate(start) {
+        return output;
+    }
+    let mut seen = HashSet::new();
+    let mut frontier = VecDeque::new();
+    seen.insert(start.to_string());
+    frontier.push_back(start.to_string());
+    while let Some(h) = frontier.pop_front() {
+        for n in neighbors(&h).into_iter().skip(1) {
+            if !seen.contains(&n) && predicate(&n) {
+                seen.insert(n.clone());
+                frontier.push_back(n);
+            }
+        }
+        output.push(h);
+        if output.len() >= max_cells {
+            break;
+        }
+    }
+    output
+}
+
+/// Get the distance in kilometers from a point to the nearest part of a GeoHash cell,
+/// which is 0 if the cell contains the point
+///
+/// # Panics
+///
+/// Panics if the GeoHash contains a character outside of the base32 alphabet.
+///
+/// # Example
+///
+/// ```
+/// let p=geohashrust::GeoLocation::from_coordinates(31.16373922, 121.62585927);
+/// assert_eq!(geohashrust::geohash_distance_to_point("wtw3r", &p), 0.0);
+/// assert!(geohashrust::geohash_distance_to_point("wtw3s", &p) > 0.0);
+/// ```
+pub fn geohash_distance_to_point(hash: &str, point: &GeoLocation) -> f64 {
+    decode(hash).distance_to_point(point)
+}
+
+/// Count the points falling into each GeoHash cell with given precision
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let points=[
+///     GeoLocation::from_coordinates(31.16373922, 121.62585927),
+///     GeoLocation::from_coordinates(31.16, 121.62),
+///     GeoLocation::from_coordinates(31.55, 121.46),
+/// ];
+/// let h=geohashrust::precision_histogram(&points, 4);
+/// assert_eq!(h["wtw3"], 2);
+/// assert_eq!(h["wtw7"], 1);
+/// ```
+pub fn precision_histogram(points: &[GeoLocation], precision: u8) -> HashMap<String, usize> {
+    let mut output = HashMap::new();
+    for p in points.iter() {
+        *output.entry(encode(p, precision)).or_insert(0) += 1;
+    }
+    output
+}
+
+// A circle on a plane, as its center and radius
+type Circle = ((f64, f64), f64);
+
+fn circle_contains(c: &Circle, p: (f64, f64)) -> bool {
+    let ((cx, cy), r) = *c;
+    ((p.0 - cx).powi(2) + (p.1 - cy).powi(2)).sqrt() <= r * (1.0 + 1e-9) + 1e-9
+}
+
+fn circle_from_2(a: (f64, f64), b: (f64, f64)) -> Circle {
+    let center = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+    (center, ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt() / 2.0)
+}
+
+fn circle_from_3(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Circle {
+    let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+    if d.abs() < 1e-12 {
+        // Collinear, the two farthest apart points span the circle
+        let candidates = [circle_from_2(a, b), circle_from_2(a, c), circle_from_2(b, c)];
+        return candidates.iter().cloned().fold(candidates[0], |m, x| if x.1 > m.1 {x} else {m});
+    }
+    let a2 = a.0 * a.0 + a.1 * a.1;
+    let b2 = b.0 * b.0 + b.1 * b.1;
+    let c2 = c.0 * c.0 + c.1 * c.1;
+    let ux = (a2 * (b.1 - c.1) + b2 * (c.1 - a.1) + c2 * (a.1 - b.1)) / d;
+    let uy = (a2 * (c.0 - b.0) + b2 * (a.0 - c.0) + c2 * (b.0 - a.0)) / d;
+    ((ux, uy), ((a.0 - ux).powi(2) + (a.1 - uy).powi(2)).sqrt())
+}
+
+/// Get the smallest circle containing all points, as its center and radius in meters
+///
+/// The circle is found with Welzl's algorithm on an equirectangular projection around
+/// the points, so it's meant for point sets spanning no more than a few hundred
+/// kilometers. The radius is the great-circle distance from the center to the
+/// farthest point. Returns `None` for no points.
+///
+/// # Example
+///
+/// ```
+/// use geohashrust::GeoLocation;
+/// let a=GeoLocation::from_coordinates(0.0, 0.0);
+/// let b=GeoLocation::from_coordinates(0.0, 0.02);
+/// let (center, radius)=geohashrust::smallest_enclosing_circle(&[a, b]).unwrap();
+/// assert!((center.longitude-0.01).abs() < 1e-9);
+/// assert!((radius-a.distance_to(&b)*500.0).abs() < 0.01);
+/// ```
+pub fn smallest_enclosing_circle(points: &[GeoLocation]) -> Option<(GeoLocation, f64)> {
+    let origin = *points.first()?;
+    let meters_per_degree = (EARTH_RADIUS * 1000.0).to_radians();
+    let lon_scale = origin.latitude.to_radians().cos().max(1e-12);
+    let project = |p: &GeoLocation| {
+        let dlon = (p.longitude - origin.longitude + 540.0) % 360.0 - 180.0;
+        (dlon * lon_scale * meters_per_degree, (p.latitude - origin.latitude) * meters_per_degree)
+    };
+    let projected: Vec<(f64, f64)> = points.iter().map(project).collect();
+
+    let mut circle: Circle = (projected[0], 0.0);
+    for i in 1..projected.len() {
+        if circle_contains(&circle, projected[i]) {
+            continue;
+        }
+        circle = (projected[i], 0.0);
+        for j in 0..i {
+            if circle_contains(&circle, projected[j]) {
+                continue;
+            }
+            circle = circle_from_2(projected[i], projected[j]);
+            for k in 0..j {
+                if !circle_contains(&circle, projected[k]) {
+                    circle = circle_from_3(projected[i], projected[j], projected[k]);
+                }
+            }
+        }
+    }
+
+    let ((x, y), _) = circle;
+    let longitude = (origin.longitude + x / lon_scale / meters_per_degree + 540.0) % 360.0 - 180.0;
+    let center = GeoLocation {
+        latitude: (origin.latitude + y / meters_per_degree).clamp(-90.0, 90.0),
+        longitude,
+    };
+    let radius = points.iter().map(|p| center.distance_to(p) * 1000.0).fold(0.0, f64::max);
+    Some((center, radius))
+}
@@ -0,0 +1,79 @@
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use geolocation::GeoLocation;
+use boundingbox::BoundingBox;
+
+// Field layout of a serialized `GeoLocation`
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "GeoLocation")]
+struct Location {
+    latitude: f64,
+    longitude: f64,
+}
+
+// Field layout of a serialized `BoundingBox`
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "BoundingBox")]
+struct Bounds {
+    min_lat: f64,
+    max_lat: f64,
+    min_lon: f64,
+    max_lon: f64,
+}
+
+/// Serialize a `GeoLocation` as a struct with `latitude` and `longitude` fields,
+/// requires the `serde` feature
+impl Serialize for GeoLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Location {
+            latitude: self.latitude,
+            longitude: self.longitude,
+        }.serialize(serializer)
+    }
+}
+
+/// Deserialize a `GeoLocation` from a struct with `latitude` and `longitude` fields,
+/// requires the `serde` feature
+///
+/// Coordinates out of range are rejected.
+impl<'de> Deserialize<'de> for GeoLocation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GeoLocation, D::Error> {
+        let l = Location::deserialize(deserializer)?;
+        GeoLocation::try_from_coordinates(l.latitude, l.longitude).map_err(D::Error::custom)
+    }
+}
+
+/// Serialize a `BoundingBox` as a struct with `min_lat`, `max_lat`, `min_lon` and
+/// `max_lon` fields, requires the `serde` feature
+impl Serialize for BoundingBox {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds {
+            min_lat: self.min_lat,
+            max_lat: self.max_lat,
+            min_lon: self.min_lon,
+            max_lon: self.max_lon,
+        }.serialize(serializer)
+    }
+}
+
+/// Deserialize a `BoundingBox` from a struct with `min_lat`, `max_lat`, `min_lon` and
+/// `max_lon` fields, requires the `serde` feature
+///
+/// Coordinates out of range, or a `min_lat` greater than `max_lat`, are rejected.
+impl<'de> Deserialize<'de> for BoundingBox {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BoundingBox, D::Error> {
+        let b = Bounds::deserialize(deserializer)?;
+        GeoLocation::try_from_coordinates(b.min_lat, b.min_lon).map_err(D::Error::custom)?;
+        GeoLocation::try_from_coordinates(b.max_lat, b.max_lon).map_err(D::Error::custom)?;
+        if b.min_lat > b.max_lat {
+            return Err(D::Error::custom(format!("min_lat {} is greater than max_lat {}", b.min_lat, b.max_lat)));
+        }
+        Ok(BoundingBox {
+            min_lat: b.min_lat,
+            max_lat: b.max_lat,
+            min_lon: b.min_lon,
+            max_lon: b.max_lon,
+        })
+    }
+}
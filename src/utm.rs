@@ -0,0 +1,172 @@
+use std::f64::consts::PI;
+
+use geolocation::GeoLocation;
+
+// WGS84 ellipsoid parameters
+static WGS84_A: f64 = 6378137.0;
+static WGS84_F: f64 = 1.0/298.257223563;
+
+// UTM scale factor on the central meridian
+static UTM_K0: f64 = 0.9996;
+static UTM_FALSE_EASTING: f64 = 500000.0;
+static UTM_FALSE_NORTHING: f64 = 10000000.0;
+
+/// A position in the Universal Transverse Mercator coordinate system
+#[derive(Default, Clone, Copy, PartialEq)]
+pub struct UtmCoordinate {
+    /// Longitude zone, from 1 to 60
+    pub zone: u8,
+    /// Hemisphere, either `'N'` or `'S'`
+    pub hemisphere: char,
+    /// Easting in meters, including the 500km false easting
+    pub easting: f64,
+    /// Northing in meters, including the 10000km false northing in the southern hemisphere
+    pub northing: f64,
+}
+
+// Get the UTM zone for a location, honoring the Norway and Svalbard exceptions
+fn utm_zone(l: &GeoLocation) -> u8 {
+    let lat = l.latitude;
+    let lon = l.longitude;
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+        return 32;
+    }
+    if (72.0..=84.0).contains(&lat) && (0.0..42.0).contains(&lon) {
+        if lon < 9.0 {
+            return 31;
+        } else if lon < 21.0 {
+            return 33;
+        } else if lon < 33.0 {
+            return 35;
+        }
+        return 37;
+    }
+    let zone = ((lon + 180.0) / 6.0).floor() as i32 + 1;
+    zone.clamp(1, 60) as u8
+}
+
+// Get the longitude of the central meridian of a zone, in radians
+fn central_meridian(zone: u8) -> f64 {
+    ((zone as f64 - 1.0) * 6.0 - 180.0 + 3.0).to_radians()
+}
+
+impl GeoLocation {
+    /// Project the location into UTM coordinates on the WGS84 ellipsoid
+    ///
+    /// The zone exceptions for southwest Norway and Svalbard are applied. UTM is
+    /// only defined between 80°S and 84°N, locations outside that band are still
+    /// projected but the result is meaningless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l=geohashrust::GeoLocation::from_coordinates(0.0, 3.0);
+    /// let utm=l.to_utm();
+    /// assert_eq!(utm.zone, 31);
+    /// assert_eq!(utm.hemisphere, 'N');
+    /// assert!((utm.easting-500000.0).abs() < 1e-6);
+    /// assert!(utm.northing.abs() < 1e-6);
+    /// ```
+    pub fn to_utm(&self) -> UtmCoordinate {
+        let zone = utm_zone(self);
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let e4 = e2 * e2;
+        let e6 = e4 * e2;
+        let ep2 = e2 / (1.0 - e2);
+
+        let lat = self.latitude.to_radians();
+        let lon = self.longitude.to_radians();
+        let mut dlon = lon - central_meridian(zone);
+        // Zones next to the antimeridian may see the longitude on the other side
+        if dlon > PI {
+            dlon -= 2.0 * PI;
+        } else if dlon < -PI {
+            dlon += 2.0 * PI;
+        }
+
+        let n = WGS84_A / (1.0 - e2 * lat.sin() * lat.sin()).sqrt();
+        let t = lat.tan() * lat.tan();
+        let c = ep2 * lat.cos() * lat.cos();
+        let a = lat.cos() * dlon;
+        let m = WGS84_A * ((1.0 - e2/4.0 - 3.0*e4/64.0 - 5.0*e6/256.0) * lat
+            - (3.0*e2/8.0 + 3.0*e4/32.0 + 45.0*e6/1024.0) * (2.0*lat).sin()
+            + (15.0*e4/256.0 + 45.0*e6/1024.0) * (4.0*lat).sin()
+            - (35.0*e6/3072.0) * (6.0*lat).sin());
+
+        let easting = UTM_K0 * n * (a
+            + (1.0 - t + c) * a.powi(3) / 6.0
+            + (5.0 - 18.0*t + t*t + 72.0*c - 58.0*ep2) * a.powi(5) / 120.0)
+            + UTM_FALSE_EASTING;
+        let mut northing = UTM_K0 * (m + n * lat.tan() * (a*a/2.0
+            + (5.0 - t + 9.0*c + 4.0*c*c) * a.powi(4) / 24.0
+            + (61.0 - 58.0*t + t*t + 600.0*c - 330.0*ep2) * a.powi(6) / 720.0));
+
+        let hemisphere = if self.latitude < 0.0 {'S'} else {'N'};
+        if hemisphere == 'S' {
+            northing += UTM_FALSE_NORTHING;
+        }
+
+        UtmCoordinate {
+            zone,
+            hemisphere,
+            easting,
+            northing,
+        }
+    }
+
+    /// Create a new `GeoLocation` from UTM coordinates on the WGS84 ellipsoid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let munich=geohashrust::GeoLocation::from_coordinates(48.1333, 11.5667);
+    /// let l=geohashrust::GeoLocation::from_utm(&munich.to_utm());
+    /// assert!((l.latitude-munich.latitude).abs() < 1e-7);
+    /// assert!((l.longitude-munich.longitude).abs() < 1e-7);
+    /// ```
+    pub fn from_utm(utm: &UtmCoordinate) -> GeoLocation {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let e4 = e2 * e2;
+        let e6 = e4 * e2;
+        let ep2 = e2 / (1.0 - e2);
+        let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+        let x = utm.easting - UTM_FALSE_EASTING;
+        let y = if utm.hemisphere == 'S' {utm.northing - UTM_FALSE_NORTHING} else {utm.northing};
+
+        let m = y / UTM_K0;
+        let mu = m / (WGS84_A * (1.0 - e2/4.0 - 3.0*e4/64.0 - 5.0*e6/256.0));
+        let lat1 = mu
+            + (3.0*e1/2.0 - 27.0*e1.powi(3)/32.0) * (2.0*mu).sin()
+            + (21.0*e1*e1/16.0 - 55.0*e1.powi(4)/32.0) * (4.0*mu).sin()
+            + (151.0*e1.powi(3)/96.0) * (6.0*mu).sin()
+            + (1097.0*e1.powi(4)/512.0) * (8.0*mu).sin();
+
+        let sin1 = lat1.sin();
+        let c1 = ep2 * lat1.cos() * lat1.cos();
+        let t1 = lat1.tan() * lat1.tan();
+        let n1 = WGS84_A / (1.0 - e2 * sin1 * sin1).sqrt();
+        let r1 = WGS84_A * (1.0 - e2) / (1.0 - e2 * sin1 * sin1).powf(1.5);
+        let d = x / (n1 * UTM_K0);
+
+        let lat = lat1 - (n1 * lat1.tan() / r1) * (d*d/2.0
+            - (5.0 + 3.0*t1 + 10.0*c1 - 4.0*c1*c1 - 9.0*ep2) * d.powi(4) / 24.0
+            + (61.0 + 90.0*t1 + 298.0*c1 + 45.0*t1*t1 - 252.0*ep2 - 3.0*c1*c1) * d.powi(6) / 720.0);
+        let dlon = (d
+            - (1.0 + 2.0*t1 + c1) * d.powi(3) / 6.0
+            + (5.0 - 2.0*c1 + 28.0*t1 - 3.0*c1*c1 + 8.0*ep2 + 24.0*t1*t1) * d.powi(5) / 120.0)
+            / lat1.cos();
+
+        let mut longitude = (central_meridian(utm.zone) + dlon).to_degrees();
+        if longitude > 180.0 {
+            longitude -= 360.0;
+        } else if longitude < -180.0 {
+            longitude += 360.0;
+        }
+
+        GeoLocation {
+            latitude: lat.to_degrees(),
+            longitude,
+        }
+    }
+}
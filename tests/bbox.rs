@@ -1,6 +1,6 @@
 extern crate geohashrust;
 
-use geohashrust::{GeoLocation, BoundingBox};
+use geohashrust::{GeoLocation, BoundingBox, cover_bounding_box, decode, encode};
 #[test]
 fn box_create() {
     let b=BoundingBox::new();
@@ -31,6 +31,37 @@ fn box_create() {
 	assert!(b.top_right().longitude==89.0);
 }
 
+#[test]
+fn box_center_and_corners() {
+	// Latitude and longitude ranges differ, so any swap shows up
+	let expected=BoundingBox::from_coordinates(-10.0, 20.0, 100.0, 180.0);
+	let boxes=[
+		expected,
+		BoundingBox::from_coordinates(20.0, -10.0, 180.0, 100.0),
+		BoundingBox::from_coordinates(20.0, -10.0, 100.0, 180.0),
+		BoundingBox::from_geolocations(&GeoLocation::from_coordinates(20.0, 100.0), &GeoLocation::from_coordinates(-10.0, 180.0)),
+		BoundingBox::from_geolocations(&GeoLocation::from_coordinates(-10.0, 180.0), &GeoLocation::from_coordinates(20.0, 100.0)),
+	];
+	for b in boxes.iter() {
+		assert!(*b==expected);
+		assert!(b.center()==GeoLocation::from_coordinates(5.0, 140.0));
+		assert!(b.top_left()==GeoLocation::from_coordinates(20.0, 100.0));
+		assert!(b.top_right()==GeoLocation::from_coordinates(20.0, 180.0));
+		assert!(b.bottom_left()==GeoLocation::from_coordinates(-10.0, 100.0));
+		assert!(b.bottom_right()==GeoLocation::from_coordinates(-10.0, 180.0));
+		assert_eq!(b.latitude_range(), 30.0);
+		assert_eq!(b.longitude_range(), 80.0);
+		assert!(b.contains(&b.center()));
+	}
+	// GeoHash cells at even precisions are twice as wide as they are tall
+	let b=decode("s");
+	assert!(b.center()==GeoLocation::from_coordinates(22.5, 22.5));
+	let b=decode("sb");
+	assert_eq!(b.longitude_range(), 11.25);
+	assert_eq!(b.latitude_range(), 5.625);
+	assert!(b.top_left()==GeoLocation::from_coordinates(5.625, 33.75));
+}
+
 #[test]
 fn box_contains() {
 	let box1=BoundingBox::from_geolocations(
@@ -80,6 +111,320 @@ fn box_contains() {
 	}));
 }
 
+#[test]
+fn box_contains_box() {
+	let outer=BoundingBox::from_coordinates(10.0, 40.0, 30.0, 60.0);
+	// Nested
+	let inner=BoundingBox::from_coordinates(20.0, 30.0, 40.0, 50.0);
+	assert!(outer.contains_box(&inner));
+	assert!(!inner.contains_box(&outer));
+	// Identical
+	assert!(outer.contains_box(&outer));
+	// Touching the edges from the inside
+	assert!(outer.contains_box(&BoundingBox::from_coordinates(10.0, 20.0, 30.0, 60.0)));
+	assert!(outer.contains_box(&BoundingBox::from_coordinates(40.0, 40.0, 45.0, 45.0)));
+	// Touching the edges from the outside
+	assert!(!outer.contains_box(&BoundingBox::from_coordinates(40.0, 50.0, 35.0, 45.0)));
+	// Partially overlapping
+	assert!(!outer.contains_box(&BoundingBox::from_coordinates(30.0, 50.0, 20.0, 40.0)));
+	assert!(!outer.contains_box(&BoundingBox::from_coordinates(20.0, 30.0, 50.0, 61.0)));
+	// Disjoint
+	assert!(!outer.contains_box(&BoundingBox::from_coordinates(50.0, 60.0, 30.0, 60.0)));
+	// Every child cell is in its parent
+	for c in "0123456789bcdefghjkmnpqrstuvwxyz".chars() {
+		assert!(decode("wtw3").contains_box(&decode(&format!("wtw3{}", c))));
+	}
+}
+
+#[test]
+fn box_intersection() {
+	let outer=BoundingBox::from_coordinates(10.0, 40.0, 30.0, 60.0);
+	// Fully nested
+	let inner=BoundingBox::from_coordinates(20.0, 30.0, 40.0, 50.0);
+	assert!(outer.intersects(&inner) && inner.intersects(&outer));
+	assert!(outer.intersection(&inner).unwrap()==inner);
+	assert!(inner.intersection(&outer).unwrap()==inner);
+	// Partially overlapping
+	let partial=BoundingBox::from_coordinates(30.0, 50.0, 20.0, 40.0);
+	assert!(outer.intersects(&partial));
+	assert!(outer.intersection(&partial).unwrap()==BoundingBox::from_coordinates(30.0, 40.0, 30.0, 40.0));
+	// Sharing an edge, or only a corner
+	let edge=BoundingBox::from_coordinates(40.0, 50.0, 35.0, 45.0);
+	assert!(outer.intersects(&edge));
+	assert!(outer.intersection(&edge).unwrap()==BoundingBox::from_coordinates(40.0, 40.0, 35.0, 45.0));
+	let corner=BoundingBox::from_coordinates(0.0, 10.0, 60.0, 70.0);
+	assert!(outer.intersection(&corner).unwrap()==BoundingBox::from_coordinates(10.0, 10.0, 60.0, 60.0));
+	// Disjoint in either direction
+	assert!(!outer.intersects(&BoundingBox::from_coordinates(41.0, 50.0, 35.0, 45.0)));
+	assert!(outer.intersection(&BoundingBox::from_coordinates(41.0, 50.0, 35.0, 45.0)).is_none());
+	assert!(outer.intersection(&BoundingBox::from_coordinates(20.0, 30.0, 61.0, 70.0)).is_none());
+	// Neighboring GeoHash cells share an edge
+	assert!(decode("wtw3s").intersection(&decode("wtw3t")).is_some());
+}
+
+#[test]
+fn box_area_km2() {
+	// The whole Earth is about 510 million km²
+	assert!((BoundingBox::world().area_km2()-510_066_000.0).abs() < 1000.0);
+	// A 1°x1° cell at the equator is about 12,364 km²
+	assert!((BoundingBox::from_coordinates(0.0, 1.0, 0.0, 1.0).area_km2()-12_364.0).abs() < 1.0);
+	// The polar cap north of 60° is about 34.17 million km²
+	assert!((BoundingBox::from_coordinates(60.0, 90.0, -180.0, 180.0).area_km2()-34_168_000.0).abs() < 1000.0);
+	// Straddling the equator adds up both halves
+	let both=BoundingBox::from_coordinates(-20.0, 30.0, 10.0, 50.0).area_km2();
+	let south=BoundingBox::from_coordinates(-20.0, 0.0, 10.0, 50.0).area_km2();
+	let north=BoundingBox::from_coordinates(0.0, 30.0, 10.0, 50.0).area_km2();
+	assert!((both-south-north).abs() < 1e-6);
+	assert_eq!(BoundingBox::from_coordinates(10.0, 20.0, 30.0, 30.0).area_km2(), 0.0);
+	assert_eq!(BoundingBox::new().area_km2(), 0.0);
+}
+
+#[test]
+fn box_expand_by_meters() {
+	for b in [BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0),
+	          BoundingBox::from_coordinates(-60.5, -60.0, -0.5, 0.5),
+	          BoundingBox::from_coordinates(48.1, 48.1, 11.5, 11.5)].iter() {
+		for meters in [10.0, 1000.0, 50000.0].iter() {
+			let e=b.expand_by_meters(*meters);
+			let c=b.center();
+			let km=meters/1000.0;
+			// Points at the radius from the middle of each edge
+			let top=GeoLocation::from_coordinates(b.max_lat, c.longitude);
+			let bottom=GeoLocation::from_coordinates(b.min_lat, c.longitude);
+			let left=GeoLocation::from_coordinates(c.latitude, b.min_lon);
+			let right=GeoLocation::from_coordinates(c.latitude, b.max_lon);
+			assert!(e.contains(&top.destination(0.0, km*(1.0-1e-9))));
+			assert!(e.contains(&bottom.destination(180.0, km*(1.0-1e-9))));
+			assert!(e.contains(&right.destination(90.0, km*(1.0-1e-9))));
+			assert!(e.contains(&left.destination(270.0, km*(1.0-1e-9))));
+			assert!(!e.contains(&top.destination(0.0, km*1.01)));
+			assert!(!e.contains(&bottom.destination(180.0, km*1.01)));
+			assert!(e.contains(&b.top_left()) && e.contains(&b.bottom_right()));
+		}
+	}
+	// Reaching a pole clamps the latitude and spans all longitudes
+	let e=BoundingBox::from_coordinates(89.99, 89.995, 10.0, 11.0).expand_by_meters(5000.0);
+	assert_eq!(e.max_lat, 90.0);
+	assert_eq!((e.min_lon, e.max_lon), (-180.0, 180.0));
+	assert!(BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0).expand_by_meters(0.0)==BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0));
+}
+
+#[test]
+fn box_to_wkt() {
+	assert_eq!(BoundingBox::world().to_wkt(), "POLYGON((-180 -90, 180 -90, 180 90, -180 90, -180 -90))");
+	assert_eq!(decode("s").to_wkt(), "POLYGON((0 0, 45 0, 45 45, 0 45, 0 0))");
+	assert_eq!(BoundingBox::from_coordinates(-33.875, -33.5, 151.25, 151.125).to_wkt(),
+		"POLYGON((151.125 -33.875, 151.25 -33.875, 151.25 -33.5, 151.125 -33.5, 151.125 -33.875))");
+	assert_eq!(BoundingBox::new().to_wkt(), "POLYGON((0 0, 0 0, 0 0, 0 0, 0 0))");
+}
+
+#[test]
+fn box_diagonal_km() {
+    // A degree on both sides at the equator, sqrt(2) degrees of arc
+    let b=BoundingBox::from_coordinates(0.0, 1.0, 0.0, 1.0);
+    assert!((b.diagonal_km()-157.2496).abs() < 1e-3);
+    // Near Shanghai, about sqrt(11.12^2 + (11.12*cos(31.15))^2)
+    let b=BoundingBox::from_coordinates(31.1, 31.2, 121.4, 121.5);
+    assert!((b.diagonal_km()-14.6357).abs() < 1e-3);
+    assert_eq!(b.diagonal_km(), b.bottom_left().distance_to(&b.top_right()));
+
+    assert_eq!(BoundingBox::from_coordinates(10.0, 10.0, 30.0, 30.0).diagonal_km(), 0.0);
+    let cell=decode("wtw3r9j");
+    // Precision 7 cells are 153m high and 131m wide at this latitude
+    assert!((cell.diagonal_km()-0.201).abs() < 1e-3);
+}
+
+#[test]
+fn box_subdivide() {
+    let b=BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+    let parts=b.subdivide(2, 3);
+    assert_eq!(parts.len(), 6);
+    // Row-major from the bottom-left corner
+    assert!(parts[0].bottom_left()==b.bottom_left());
+    assert!(parts[2].bottom_right()==b.bottom_right());
+    assert!(parts[3].top_left()==b.top_left());
+    assert!(parts[5].top_right()==b.top_right());
+    assert!(parts[1].max_lon==parts[2].min_lon && parts[1].max_lat==parts[4].min_lat);
+
+    let mut area=0.0;
+    for p in parts.iter() {
+        assert!(b.contains_box(p));
+        assert!((p.latitude_range()-5.0).abs() < 1e-9);
+        assert!((p.longitude_range()-10.0/3.0).abs() < 1e-9);
+        area+=p.area_km2();
+    }
+    assert!((area-b.area_km2()).abs() < 1e-6*area);
+
+    assert!(b.subdivide(1, 1)[0]==b);
+    assert!(b.subdivide(0, 3).is_empty());
+}
+
+#[test]
+fn box_wrapping_antimeridian() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    assert!(b.wraps_antimeridian());
+    assert!(b.contains(&GeoLocation::from_coordinates(-15.0, 175.0)));
+    assert!(b.contains(&GeoLocation::from_coordinates(-15.0, -175.0)));
+    assert!(b.contains(&GeoLocation::from_coordinates(-15.0, 180.0)));
+    assert!(b.contains(&GeoLocation::from_coordinates(-15.0, -180.0)));
+    assert!(b.contains(&GeoLocation::from_coordinates(-20.0, 170.0)));
+    assert!(!b.contains(&GeoLocation::from_coordinates(-15.0, 0.0)));
+    assert!(!b.contains(&GeoLocation::from_coordinates(-15.0, 169.0)));
+    assert!(!b.contains(&GeoLocation::from_coordinates(-25.0, 175.0)));
+    assert_eq!(b.longitude_range(), 20.0);
+    assert!(b.center()==GeoLocation::from_coordinates(-15.0, 180.0));
+    let off=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 160.0, -170.0);
+    assert!(off.center()==GeoLocation::from_coordinates(-15.0, 175.0));
+    let off=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -160.0);
+    assert!(off.center()==GeoLocation::from_coordinates(-15.0, -175.0));
+
+    // The plain constructor sorts the longitudes, giving the box the other way round
+    let b=BoundingBox::from_coordinates(-20.0, -10.0, 170.0, -170.0);
+    assert!(!b.wraps_antimeridian());
+    assert_eq!(b.longitude_range(), 340.0);
+    assert!(b.contains(&GeoLocation::from_coordinates(-15.0, 0.0)));
+    assert!(!b.contains(&GeoLocation::from_coordinates(-15.0, 175.0)));
+
+    // In order the longitudes give a box like from_coordinates
+    let b=BoundingBox::from_coordinates_wrapping(-10.0, -20.0, -170.0, 170.0);
+    assert!(b==BoundingBox::from_coordinates(-20.0, -10.0, 170.0, -170.0));
+}
+
+#[test]
+fn wrapped_box_overlaps() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    let east=BoundingBox::from_coordinates(-15.0, -5.0, 175.0, 180.0);
+    let west=BoundingBox::from_coordinates(-15.0, -5.0, -180.0, -175.0);
+    let far=BoundingBox::from_coordinates(-15.0, -5.0, 0.0, 10.0);
+    assert!(b.intersects(&east) && east.intersects(&b));
+    assert!(b.intersects(&west) && west.intersects(&b));
+    assert!(!b.intersects(&far) && !far.intersects(&b));
+    assert!(b.intersection(&east).unwrap()==BoundingBox::from_coordinates(-15.0, -10.0, 175.0, 180.0));
+    assert!(b.intersection(&west).unwrap()==BoundingBox::from_coordinates(-15.0, -10.0, -180.0, -175.0));
+    assert!(b.intersection(&far).is_none());
+
+    // Two wrapped boxes overlap in a wrapped box
+    let other=BoundingBox::from_coordinates_wrapping(-15.0, -5.0, 175.0, -160.0);
+    assert!(b.intersects(&other));
+    assert!(b.intersection(&other).unwrap()==BoundingBox::from_coordinates_wrapping(-15.0, -10.0, 175.0, -170.0));
+
+    // Reaching the antimeridian from both sides overlaps in 2 pieces, not a box
+    let across=BoundingBox::from_coordinates(-15.0, -5.0, -175.0, 175.0);
+    assert!(b.intersects(&across));
+    assert!(b.intersection(&across).is_none());
+    assert!(across.intersection(&b).is_none());
+
+    assert!(b.contains_box(&BoundingBox::from_coordinates(-18.0, -12.0, 175.0, 180.0)));
+    assert!(b.contains_box(&BoundingBox::from_coordinates_wrapping(-18.0, -12.0, 175.0, -175.0)));
+    assert!(!b.contains_box(&other));
+    assert!(!east.contains_box(&b));
+}
+
+#[test]
+fn wrapped_box_relative_position() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, 175.0)), (0.25, 0.5));
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, 180.0)), (0.5, 0.5));
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, -180.0)), (0.5, 0.5));
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, -175.0)), (0.75, 0.5));
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, -170.0)), (1.0, 0.5));
+    // Outside of the box, clamped to the closer edge
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, -160.0)), (1.0, 0.5));
+    assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-15.0, 160.0)), (0.0, 0.5));
+}
+
+#[test]
+fn wrapped_box_merge() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    // Merging goes the short way around
+    let m=BoundingBox::merged(&b, &BoundingBox::from_coordinates(0.0, 5.0, -160.0, -150.0));
+    assert!(m==BoundingBox::from_coordinates_wrapping(-20.0, 5.0, 170.0, -150.0));
+    let m=BoundingBox::merged(&b, &BoundingBox::from_coordinates(0.0, 5.0, 150.0, 160.0));
+    assert!(m==BoundingBox::from_coordinates_wrapping(-20.0, 5.0, 150.0, -170.0));
+    // Boxes together going all the way round give the whole world
+    let m=BoundingBox::merged(&b, &BoundingBox::from_coordinates(0.0, 5.0, -175.0, 175.0));
+    assert!(m==BoundingBox::from_coordinates(-20.0, 5.0, -180.0, 180.0));
+
+    let mut e=b;
+    e.expand_to_include(&GeoLocation::from_coordinates(-30.0, -165.0));
+    assert!(e==BoundingBox::from_coordinates_wrapping(-30.0, -10.0, 170.0, -165.0));
+    let mut e=b;
+    e.expand_to_include(&GeoLocation::from_coordinates(-15.0, 165.0));
+    assert!(e==BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 165.0, -170.0));
+    let mut e=b;
+    e.expand_to_include(&GeoLocation::from_coordinates(-15.0, 180.0));
+    assert!(e==b);
+
+    let e=b.expand_by_meters(1000.0);
+    assert!(e.wraps_antimeridian());
+    assert!(e.min_lon<170.0 && e.max_lon>-170.0);
+    assert!(e.min_lat < -20.0 && e.max_lat > -10.0);
+    let e=b.expand_by_meters(20_000_000.0);
+    assert!(e.min_lon==-180.0 && e.max_lon==180.0);
+}
+
+#[test]
+fn wrapped_box_distance() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    assert_eq!(b.distance_to_point(&GeoLocation::from_coordinates(-15.0, 175.0)), 0.0);
+    assert_eq!(b.distance_to_point(&GeoLocation::from_coordinates(-15.0, -175.0)), 0.0);
+    let p=GeoLocation::from_coordinates(-15.0, -165.0);
+    let expected=p.distance_to(&GeoLocation::from_coordinates(-15.0, -170.0));
+    assert!((b.distance_to_point(&p)-expected).abs()<1e-9);
+    assert!((p.distance_to_box(&b)-expected).abs()<1e-9);
+    let p=GeoLocation::from_coordinates(-15.0, 165.0);
+    let expected=p.distance_to(&GeoLocation::from_coordinates(-15.0, 170.0));
+    assert!((b.distance_to_point(&p)-expected).abs()<1e-9);
+
+    // Measures the same as the box the same size centered on the prime meridian
+    let shifted=BoundingBox::from_coordinates(-20.0, -10.0, -10.0, 10.0);
+    assert!((b.diagonal_km()-shifted.diagonal_km()).abs()<1e-9);
+}
+
+#[test]
+fn wrapped_box_shapes() {
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    let parts=b.subdivide(1, 4);
+    assert_eq!(parts.len(), 4);
+    assert!(parts[0]==BoundingBox::from_coordinates(-20.0, -10.0, 170.0, 175.0));
+    assert!(parts[1]==BoundingBox::from_coordinates(-20.0, -10.0, 175.0, 180.0));
+    assert!(parts[2]==BoundingBox::from_coordinates(-20.0, -10.0, -180.0, -175.0));
+    assert!(parts[3]==BoundingBox::from_coordinates(-20.0, -10.0, -175.0, -170.0));
+    let parts=b.subdivide(1, 3);
+    assert!(parts[1].wraps_antimeridian());
+    assert!(parts[2].max_lon==-170.0);
+
+    let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+    assert!(b.align_to_geohash_grid(1)==BoundingBox::from_coordinates_wrapping(-45.0, 0.0, 135.0, -135.0));
+}
+
+#[test]
+fn box_from_points() {
+	let points=[
+		GeoLocation::from_coordinates(31.16, 121.62),
+		GeoLocation::from_coordinates(-33.87, 151.21),
+		GeoLocation::from_coordinates(48.13, 11.57),
+	];
+	let b=BoundingBox::from_points(&points).unwrap();
+	assert!(b==BoundingBox::from_coordinates(-33.87, 48.13, 11.57, 151.21));
+	for p in points.iter() {
+		assert!(b.contains(p));
+	}
+	// Same as growing a box one point at a time
+	let mut grown=BoundingBox::from_geolocations(&points[2], &points[2]);
+	grown.expand_to_include(&points[0]);
+	assert!(grown==BoundingBox::from_coordinates(31.16, 48.13, 11.57, 121.62));
+	grown.expand_to_include(&points[1]);
+	assert!(grown==b);
+	// Points already inside don't change it
+	grown.expand_to_include(&GeoLocation::from_coordinates(0.0, 100.0));
+	assert!(grown==b);
+
+	let single=BoundingBox::from_points(&points[..1]).unwrap();
+	assert!(single.top_left()==points[0] && single.bottom_right()==points[0]);
+	assert!(BoundingBox::from_points(&[]).is_none());
+}
+
 #[test]
 fn box_expand() {
 	let mut box1=BoundingBox::from_geolocations(
@@ -108,3 +453,80 @@ fn box_expand() {
 	assert!(box1.max_lat==123.0);
 	assert!(box1.max_lon==145.0);
 }
+
+#[test]
+fn box_world() {
+	let w=BoundingBox::world();
+	for &(lat, lon) in [(0.0, 0.0), (90.0, 180.0), (-90.0, -180.0), (31.23, 121.473), (-33.87, 151.21), (40.71, -74.0)].iter() {
+		assert!(w.contains(&GeoLocation::from_coordinates(lat, lon)));
+	}
+	// The default box is a point, not the world
+	assert!(BoundingBox::default()==BoundingBox::new());
+	assert!(!BoundingBox::default().contains(&GeoLocation::from_coordinates(1.0, 1.0)));
+
+	// Union of nothing is nothing, the world swallows everything else
+	let none: Vec<BoundingBox>=vec![];
+	assert!(BoundingBox::union(&none).is_none());
+	assert!(BoundingBox::union(&[w]).unwrap()==w);
+	let b=BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+	assert!(BoundingBox::union(&[b, w]).unwrap()==w);
+	assert!(BoundingBox::union(&[b]).unwrap()==b);
+}
+
+#[test]
+fn box_relative_position() {
+	let b=BoundingBox::from_coordinates(23.0, 67.0, 45.0, 89.0);
+	assert_eq!(b.relative_position(&b.center()), (0.5, 0.5));
+	assert_eq!(b.relative_position(&b.top_right()), (1.0, 1.0));
+	assert_eq!(b.relative_position(&b.bottom_left()), (0.0, 0.0));
+	assert_eq!(b.relative_position(&b.top_left()), (0.0, 1.0));
+	assert_eq!(b.relative_position(&b.bottom_right()), (1.0, 0.0));
+	assert_eq!(b.relative_position(&GeoLocation::from_coordinates(34.0, 78.0)), (0.75, 0.25));
+	// Clamped
+	assert_eq!(b.relative_position(&GeoLocation::from_coordinates(-80.0, 100.0)), (1.0, 0.0));
+	// Degenerate
+	let p=GeoLocation::from_coordinates(10.0, 20.0);
+	let point=BoundingBox::from_geolocations(&p, &p);
+	assert_eq!(point.relative_position(&GeoLocation::from_coordinates(0.0, 0.0)), (0.5, 0.5));
+}
+
+#[test]
+fn box_align_to_geohash_grid() {
+	let b=BoundingBox::from_coordinates(31.12, 31.21, 121.55, 121.71);
+	for precision in 1..7 {
+		let aligned=b.align_to_geohash_grid(precision);
+		assert!(aligned.contains(&b.bottom_left()) && aligned.contains(&b.top_right()));
+
+		// Same as the box of the covering cells
+		let cells: Vec<BoundingBox>=cover_bounding_box(&b, precision).iter().map(|h| decode(h)).collect();
+		assert!(BoundingBox::union(&cells).unwrap()==aligned);
+
+		// Corners lie on the borders of the corner cells
+		let bottom_left=decode(&encode(&b.bottom_left(), precision));
+		let top_right=decode(&encode(&b.top_right(), precision));
+		assert!(bottom_left.bottom_left()==aligned.bottom_left());
+		assert!(top_right.top_right()==aligned.top_right());
+	}
+	// Aligned boxes stay put
+	let cell=decode("wtw3s");
+	assert!(cell.align_to_geohash_grid(5)==cell);
+}
+
+#[test]
+fn box_from_geohashes() {
+	assert!(BoundingBox::from_geohashes(&[]).is_none());
+	let cell=decode("wtw3s");
+	assert!(BoundingBox::from_geohashes(&["wtw3s".to_string()]).unwrap()==cell);
+
+	// Three adjacent cells in a row
+	let hashes=vec!["wtw3e".to_string(), "wtw3s".to_string(), "wtw3t".to_string()];
+	let b=BoundingBox::from_geohashes(&hashes).unwrap();
+	assert!(b==BoundingBox::from_coordinates(cell.min_lat, cell.max_lat,
+	                                         decode("wtw3e").min_lon, decode("wtw3t").max_lon));
+	assert!((b.longitude_range()-3.0*cell.longitude_range()).abs() < 1e-12);
+
+	// Cells across the antimeridian span the world
+	let b=BoundingBox::from_geohashes(&["zzz".to_string(), "bpb".to_string()]).unwrap();
+	assert_eq!(b.min_lon, -180.0);
+	assert_eq!(b.max_lon, 180.0);
+}
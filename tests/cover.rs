@@ -0,0 +1,303 @@
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, BoundingBox, GeohashError, cover_bounding_box, coverage_efficiency, covering_diff,
+                  serialize_covering, deserialize_covering, covering_centroid, cover_circle,
+                  cells_inside_circle, circle_coverage_ratio, cover_symmetric_difference,
+                  find_overlaps, covering_jaccard, geohashes_in_box, flood_fill, encode, decode};
+
+#[test]
+fn test_cover_bounding_box() {
+	let b=BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6);
+	let cells=cover_bounding_box(&b, 5);
+	for h in cells.iter() {
+		assert_eq!(h.len(), 5);
+	}
+	// Every corner and the center are covered
+	for p in [b.top_left(), b.top_right(), b.bottom_left(), b.bottom_right(), b.center()].iter() {
+		assert!(cells.iter().any(|h| decode(h).contains(p)));
+	}
+
+	// A box smaller than a cell still gets a cell
+	let p=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let b=BoundingBox::from_geolocations(&p, &p);
+	assert_eq!(cover_bounding_box(&b, 7), vec!["wtw3r9j"]);
+}
+
+#[test]
+fn test_cover_wrapped_box() {
+	// The cells of both halves, going east across the antimeridian
+	let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+	let mut cells=cover_bounding_box(&b, 3);
+	assert!(!cells.is_empty());
+	assert!(cells.iter().any(|h| decode(h).contains(&GeoLocation::from_coordinates(-15.0, 179.9))));
+	assert!(cells.iter().any(|h| decode(h).contains(&GeoLocation::from_coordinates(-15.0, -179.9))));
+	let mut halves=cover_bounding_box(&BoundingBox::from_coordinates(-20.0, -10.0, 170.0, 180.0), 3);
+	halves.extend(cover_bounding_box(&BoundingBox::from_coordinates(-20.0, -10.0, -180.0, -170.0), 3));
+	cells.sort();
+	halves.sort();
+	assert_eq!(cells, halves);
+
+	// A box wrapping all the way round covers every column once
+	let b=BoundingBox::from_coordinates_wrapping(0.0, 1.0, -1.0, -2.0);
+	assert_eq!(cover_bounding_box(&b, 1).len(), 8);
+
+	// Cells across the antimeridian only in one of the boxes
+	let a=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+	let b=BoundingBox::from_coordinates(-20.0, -10.0, 170.0, 180.0);
+	let cells=cover_symmetric_difference(&a, &b, 3);
+	assert!(!cells.is_empty());
+	assert!(cells.iter().all(|h| decode(h).max_lon<=-170.0+1.5));
+}
+
+#[test]
+fn test_cover_bounding_box_walk() {
+	// Same cells as walking the neighbors from the bottom-left corner
+	for (b, precision) in [(BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6), 5),
+	                       (BoundingBox::from_coordinates(-0.3, 0.2, -0.1, 0.4), 4),
+	                       (BoundingBox::from_coordinates(47.001, 47.002, 8.001, 8.002), 6)].iter() {
+		let mut cells=cover_bounding_box(b, *precision);
+		let mut walked=flood_fill(&encode(&b.bottom_left(), *precision), 10000, |h| {
+			let c=decode(h);
+			c.min_lat<b.max_lat && b.min_lat<c.max_lat && c.min_lon<b.max_lon && b.min_lon<c.max_lon
+		});
+		cells.sort();
+		walked.sort();
+		assert_eq!(cells, walked);
+		let mut unique=cells.clone();
+		unique.dedup();
+		assert_eq!(unique.len(), cells.len());
+	}
+	// A box inside a single cell, or even a single point
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	assert_eq!(cover_bounding_box(&BoundingBox::from_geolocations(&l, &l), 7), vec![encode(&l, 7)]);
+	assert_eq!(cover_bounding_box(&decode("wtw3r9j"), 7), vec!["wtw3r9j"]);
+}
+
+#[test]
+fn test_geohashes_in_box() {
+	let b=BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6);
+	assert_eq!(geohashes_in_box(&b, 5), cover_bounding_box(&b, 5));
+	let b=BoundingBox::from_coordinates_wrapping(-20.0, -10.0, 170.0, -170.0);
+	assert_eq!(geohashes_in_box(&b, 3), cover_bounding_box(&b, 3));
+}
+
+#[test]
+fn test_coverage_efficiency() {
+	// Aligned to the grid
+	let b=decode("wtw3");
+	assert!((coverage_efficiency(&b, 4)-1.0).abs() < 1e-9);
+	assert!((coverage_efficiency(&b, 6)-1.0).abs() < 1e-9);
+
+	// A small box around a cell corner straddles the borders of 4 cells and wastes most
+	// of the covering
+	let c=decode("wtw3s").top_right();
+	let b=BoundingBox::from_coordinates(c.latitude-0.01, c.latitude+0.01, c.longitude-0.01, c.longitude+0.01);
+	assert_eq!(cover_bounding_box(&b, 5).len(), 4);
+	let e=coverage_efficiency(&b, 5);
+	assert!(e > 0.0 && e < 0.5);
+	assert!(coverage_efficiency(&b, 7) > e);
+
+	// A single point has no area
+	let b=BoundingBox::from_geolocations(&c, &c);
+	assert_eq!(coverage_efficiency(&b, 5), 0.0);
+}
+
+#[test]
+fn test_covering_diff() {
+	// Pan a viewport east by about half its width
+	let old=cover_bounding_box(&BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6), 5);
+	let new=cover_bounding_box(&BoundingBox::from_coordinates(31.1, 31.3, 121.5, 121.7), 5);
+	let (added, removed)=covering_diff(&old, &new);
+	assert!(!added.is_empty());
+	assert!(!removed.is_empty());
+	for h in added.iter() {
+		assert!(new.contains(h) && !old.contains(h));
+	}
+	for h in removed.iter() {
+		assert!(old.contains(h) && !new.contains(h));
+	}
+	let kept=new.iter().filter(|h| old.contains(h)).count();
+	assert_eq!(kept+added.len(), new.len());
+	assert_eq!(kept+removed.len(), old.len());
+
+	let (added, removed)=covering_diff(&old, &old);
+	assert!(added.is_empty() && removed.is_empty());
+}
+
+#[test]
+fn test_serialize_covering() {
+	let hashes=cover_bounding_box(&BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6), 6);
+	assert!(hashes.len() > 200);
+	let bytes=serialize_covering(&hashes);
+	assert!(bytes.len() < hashes.len()*4);
+	assert_eq!(deserialize_covering(&bytes).unwrap(), hashes);
+
+	// Nothing shared
+	let hashes=vec!["0000".to_string(), "zzzz".to_string(), "wtw3".to_string()];
+	let bytes=serialize_covering(&hashes);
+	assert_eq!(bytes[1], 0);
+	assert_eq!(deserialize_covering(&bytes).unwrap(), hashes);
+
+	// A single GeoHash is all prefix
+	let hashes=vec!["wtw3r9jjzyjc".to_string()];
+	let bytes=serialize_covering(&hashes);
+	assert_eq!(bytes[0], 12);
+	assert_eq!(bytes[1], 12);
+	assert_eq!(deserialize_covering(&bytes).unwrap(), hashes);
+
+	let empty: Vec<String>=vec![];
+	assert_eq!(serialize_covering(&empty), vec![0, 0, 0, 0, 0, 0]);
+	assert_eq!(deserialize_covering(&serialize_covering(&empty)).unwrap(), empty);
+
+	assert!(deserialize_covering(&[]).is_err());
+	assert!(deserialize_covering(&[5, 6, 1, 0, 0, 0, 0, 0, 0, 0]).is_err());
+	let mut bytes=serialize_covering(&["wtw3s".to_string(), "wtw37".to_string()]);
+	bytes.push(0);
+	assert!(deserialize_covering(&bytes).is_err());
+
+	// Duplicates keep a character of their own
+	let hashes=vec!["wtw3s".to_string(), "wtw3s".to_string()];
+	let bytes=serialize_covering(&hashes);
+	assert_eq!(bytes[1], 4);
+	assert_eq!(deserialize_covering(&bytes).unwrap(), hashes);
+
+	// A huge count with nothing to read for each GeoHash is rejected right away
+	assert_eq!(deserialize_covering(&[0, 0, 0xff, 0xff, 0xff, 0xff]), Err(GeohashError::MalformedData));
+	assert_eq!(deserialize_covering(&[3, 3, 0xff, 0xff, 0xff, 0xff, 0, 0]), Err(GeohashError::MalformedData));
+	// And so is one the data is too short for
+	assert_eq!(deserialize_covering(&[255, 0, 0xff, 0xff, 0xff, 0xff, 0]), Err(GeohashError::MalformedData));
+}
+
+#[test]
+fn test_border_cells() {
+	let b=BoundingBox::from_coordinates(31.1, 31.3, 121.4, 121.6);
+	let all=cover_bounding_box(&b, 6);
+	let border=b.border_cells(6);
+	assert!(border.len() < all.len());
+	for h in border.iter() {
+		assert!(all.contains(h));
+	}
+	// Interior cells have all 8 neighbors inside the covering
+	for h in all.iter() {
+		let interior=geohashrust::neighbors(h).iter().all(|n| all.contains(n));
+		assert_eq!(border.contains(h), !interior, "{}", h);
+	}
+}
+
+#[test]
+fn test_covering_centroid() {
+	assert!(covering_centroid(&[]).is_none());
+
+	// A covering symmetric about the equator
+	let b=BoundingBox::from_coordinates(-11.25, 11.25, 0.0, 22.5);
+	let hashes=cover_bounding_box(&b, 2);
+	assert_eq!(hashes.len(), 8);
+	let c=covering_centroid(&hashes).unwrap();
+	assert!(c.latitude.abs() < 1e-9);
+	assert!((c.longitude-11.25).abs() < 1e-9);
+
+	// Cells closer to the pole are smaller and weigh less
+	let hashes=vec!["s".to_string(), "u".to_string()];
+	let c=covering_centroid(&hashes).unwrap();
+	let mean=(decode("s").center().latitude+decode("u").center().latitude)/2.0;
+	assert!(c.latitude < mean);
+	assert!(c.latitude > decode("s").center().latitude);
+}
+
+#[test]
+fn test_cells_inside_circle() {
+	let center=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let radius=20000.0;
+	let inside=cells_inside_circle(&center, radius, 6);
+	let overlapping=cover_circle(&center, radius, 6);
+	assert!(!inside.is_empty());
+	assert!(inside.len() < overlapping.len());
+
+	// Every corner, so the farthest one, is within the radius
+	for h in inside.iter() {
+		let b=decode(h);
+		let farthest=[b.top_left(), b.top_right(), b.bottom_left(), b.bottom_right()].iter()
+			.map(|c| center.distance_to(c)*1000.0)
+			.fold(0.0, f64::max);
+		assert!(farthest <= radius);
+		assert!(overlapping.contains(h));
+	}
+	// Cells crossing the circle are left out
+	for h in overlapping.iter().filter(|h| !inside.contains(h)) {
+		let b=decode(h);
+		assert!(b.distance_to_point(&center)*1000.0 <= radius);
+		assert!([b.top_left(), b.top_right(), b.bottom_left(), b.bottom_right()].iter()
+			.any(|c| center.distance_to(c)*1000.0 > radius));
+	}
+
+	assert!(cells_inside_circle(&center, 0.0, 6).is_empty());
+	assert_eq!(cover_circle(&center, 0.0, 6), vec![encode(&center, 6)]);
+}
+
+#[test]
+fn test_circle_coverage_ratio() {
+	let center=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let ratios: Vec<f64>=(3..9).map(|p| circle_coverage_ratio(&center, 2000.0, p)).collect();
+	assert!(ratios.windows(2).all(|w| w[0] < w[1]));
+	assert!(ratios.iter().all(|&r| r > 0.0 && r <= 1.0));
+	assert!(ratios[5] > 0.95);
+}
+
+#[test]
+fn test_cover_symmetric_difference() {
+	let a=BoundingBox::from_coordinates(31.10, 31.30, 121.50, 121.70);
+	let b=BoundingBox::from_coordinates(31.20, 31.40, 121.60, 121.80);
+	let cells=cover_symmetric_difference(&a, &b, 5);
+
+	// Same as diffing the two coverings, without the cells in the overlap
+	let cover_a=cover_bounding_box(&a, 5);
+	let cover_b=cover_bounding_box(&b, 5);
+	let (added, removed)=covering_diff(&cover_a, &cover_b);
+	assert_eq!(cells.len(), added.len()+removed.len());
+	assert!(added.iter().chain(removed.iter()).all(|h| cells.contains(h)));
+	assert!(cells.iter().all(|h| !(cover_a.contains(h) && cover_b.contains(h))));
+	assert!(!cells.contains(&encode(&GeoLocation::from_coordinates(31.25, 121.65), 5)));
+
+	assert!(cover_symmetric_difference(&a, &a, 5).is_empty());
+	// Disjoint boxes give both coverings
+	let far=decode("9q8yy");
+	let mut both=cover_a.clone();
+	both.push("9q8yy".to_string());
+	assert_eq!(cover_symmetric_difference(&a, &far, 5), both);
+}
+
+#[test]
+fn test_find_overlaps() {
+	// A clean covering
+	let hashes=cover_bounding_box(&decode("wtw3"), 6);
+	assert!(find_overlaps(&hashes).is_empty());
+	assert!(find_overlaps(&[]).is_empty());
+
+	// A parent along with its child, and a duplicate
+	let mut hashes=hashes;
+	hashes.push("wtw3s".to_string());
+	let n=hashes.len();
+	let child=hashes.iter().position(|h| h.starts_with("wtw3s")).unwrap();
+	let overlaps=find_overlaps(&hashes);
+	assert_eq!(overlaps.len(), 32);
+	assert!(overlaps.contains(&(child, n-1)));
+	assert!(overlaps.iter().all(|&(_, j)| j==n-1));
+
+	let hashes=vec!["wtw3s".to_string(), "9q8yy".to_string(), "WTW3S".to_string(), "w".to_string()];
+	assert_eq!(find_overlaps(&hashes), vec![(0, 2), (0, 3), (2, 3)]);
+}
+
+#[test]
+fn test_covering_jaccard() {
+	let a=cover_bounding_box(&decode("wtw3"), 6);
+	assert_eq!(covering_jaccard(&a, &a), 1.0);
+	assert_eq!(covering_jaccard(&[], &[]), 1.0);
+	assert_eq!(covering_jaccard(&a, &[]), 0.0);
+	assert_eq!(covering_jaccard(&a, &cover_bounding_box(&decode("wtw6"), 6)), 0.0);
+
+	// Half of one covering, duplicates don't count
+	let mut half: Vec<String>=a[..a.len()/2].to_vec();
+	half.push(a[0].clone());
+	assert_eq!(covering_jaccard(&a, &half), 0.5);
+	assert_eq!(covering_jaccard(&half, &a), 0.5);
+}
@@ -1,6 +1,8 @@
 extern crate geohashrust;
 
-use geohashrust::GeoLocation;
+use std::collections::HashSet;
+
+use geohashrust::{GeoLocation, BoundingBox, GeohashError, CoordinateError, EARTH_RADIUS};
 
 #[test]
 fn test_geolocation() {
@@ -12,6 +14,95 @@ fn test_geolocation() {
     assert_eq!(l.longitude, 11.5667);
 }
 
+#[test]
+fn try_from_coordinates() {
+    let l = GeoLocation::try_from_coordinates(48.1333, 11.5667).unwrap();
+    assert!(l == GeoLocation::from_coordinates(48.1333, 11.5667));
+    assert!(GeoLocation::try_from_coordinates(-90.0, 180.0).is_ok());
+    assert_eq!(GeoLocation::try_from_coordinates(-90.1, 0.0).err(), Some(CoordinateError::InvalidLatitude(-90.1)));
+    assert_eq!(GeoLocation::try_from_coordinates(0.0, 180.1).err(), Some(CoordinateError::InvalidLongitude(180.1)));
+    // Latitude is checked first
+    assert_eq!(GeoLocation::try_from_coordinates(100.0, 200.0).err(), Some(CoordinateError::InvalidLatitude(100.0)));
+    assert!(GeoLocation::try_from_coordinates(f64::NAN, 0.0).is_err());
+
+    let e = GeoLocation::try_from_coordinates(0.0, 200.0).err().unwrap();
+    assert_eq!(e.to_string(), "Longitude 200 out of range");
+    assert_eq!(GeohashError::from(e), GeohashError::InvalidLongitude(200.0));
+}
+
+#[test]
+fn quantized() {
+    let a = GeoLocation::from_coordinates(31.16373922, 121.62585927);
+    let b = GeoLocation::from_coordinates(31.16371, 121.62589);
+    let c = GeoLocation::from_coordinates(31.1638, 121.6259);
+    assert_eq!(a.quantized(4), b.quantized(4));
+    assert!(a.quantized(4) != c.quantized(4));
+    assert!(a.quantized(5) != b.quantized(5));
+    // Different numbers of decimals never collide
+    assert!(GeoLocation::new().quantized(2) != GeoLocation::new().quantized(3));
+    assert_eq!(GeoLocation::from_coordinates(-0.00001, 0.0).quantized(4), GeoLocation::new().quantized(4));
+
+    let mut set = HashSet::new();
+    set.insert(a.quantized(4));
+    set.insert(b.quantized(4));
+    set.insert(c.quantized(4));
+    assert_eq!(set.len(), 2);
+
+    let q = a.quantized(4);
+    assert_eq!(q.decimals(), 4);
+    assert_eq!(q.location().latitude, 31.1637);
+    assert_eq!(q.location().longitude, 121.6259);
+}
+
+#[test]
+fn normalize() {
+    let l = GeoLocation { latitude: 10.0, longitude: 181.0 }.normalize();
+    assert!(l == GeoLocation::from_coordinates(10.0, -179.0));
+    let l = GeoLocation { latitude: -10.0, longitude: -185.0 }.normalize();
+    assert!(l == GeoLocation::from_coordinates(-10.0, 175.0));
+    let l = GeoLocation { latitude: 95.0, longitude: 0.0 }.normalize();
+    assert!(l == GeoLocation::from_coordinates(90.0, 0.0));
+    let l = GeoLocation { latitude: -100.0, longitude: 900.0 }.normalize();
+    assert!(l == GeoLocation::from_coordinates(-90.0, -180.0));
+
+    // In range stays as is, including both sides of the antimeridian
+    for l in [GeoLocation::from_coordinates(48.1333, 11.5667), GeoLocation::from_coordinates(90.0, 180.0),
+              GeoLocation::from_coordinates(-90.0, -180.0)].iter() {
+        assert!(l.normalize() == *l);
+    }
+    // Wrapping by whole turns
+    let l = GeoLocation { latitude: 48.1333, longitude: 11.5667 + 720.0 }.normalize();
+    assert!((l.longitude - 11.5667).abs() < 1e-9);
+}
+
+#[test]
+fn display() {
+    let l = GeoLocation::from_coordinates(31.23, 121.473);
+    assert_eq!(format!("{}", l), "(31.23, 121.473)");
+    assert_eq!(format!("{:.2}", l), "(31.23, 121.47)");
+    assert_eq!(format!("{:.0}", l), "(31, 121)");
+    assert_eq!(format!("{:.4}", GeoLocation::from_coordinates(-33.8688, -151.2093)), "(-33.8688, -151.2093)");
+    assert_eq!(GeoLocation::new().to_string(), "(0, 0)");
+}
+
+#[test]
+fn parse() {
+    let l: GeoLocation = "48.1333,11.5667".parse().unwrap();
+    assert!(l == GeoLocation::from_coordinates(48.1333, 11.5667));
+    let l: GeoLocation = "  -33.8688 ,\t151.2093\n".parse().unwrap();
+    assert!(l == GeoLocation::from_coordinates(-33.8688, 151.2093));
+    let l: GeoLocation = "90,-180".parse().unwrap();
+    assert!(l == GeoLocation::from_coordinates(90.0, -180.0));
+
+    assert_eq!("48.1333 11.5667".parse::<GeoLocation>().err(), Some(GeohashError::MalformedData));
+    assert_eq!("48.1333,11.5667,0".parse::<GeoLocation>().err(), Some(GeohashError::MalformedData));
+    assert_eq!("north,11.5667".parse::<GeoLocation>().err(), Some(GeohashError::MalformedData));
+    assert_eq!("48.1333,".parse::<GeoLocation>().err(), Some(GeohashError::MalformedData));
+    assert_eq!("".parse::<GeoLocation>().err(), Some(GeohashError::MalformedData));
+    assert_eq!("90.5,0".parse::<GeoLocation>().err(), Some(GeohashError::InvalidLatitude(90.5)));
+    assert_eq!("0,-180.5".parse::<GeoLocation>().err(), Some(GeohashError::InvalidLongitude(-180.5)));
+}
+
 #[test]
 fn distance() {
     let new_york = GeoLocation::from_coordinates(40.7127, -74.0059);
@@ -22,6 +113,62 @@ fn distance() {
     assert_eq!(munich.distance_to(&helsinki).round(), 1590.1646151045206_f64.round());
 }
 
+#[test]
+fn distance_units() {
+    let new_york = GeoLocation::from_coordinates(40.7127, -74.0059);
+    let helsinki = GeoLocation::from_coordinates(60.1708, 24.9375);
+    let munich = GeoLocation::from_coordinates(48.1333, 11.5667);
+
+    for (a, b) in [(new_york, helsinki), (munich, helsinki), (munich, munich)].iter() {
+        assert_eq!(a.distance_to_meters(b), a.distance_to(b) * 1000.0);
+        assert!((a.distance_to_miles(b) * 1.609344 - a.distance_to(b)).abs() < 1e-9);
+    }
+    assert_eq!(munich.distance_to_miles(&helsinki).round(), 988.0);
+}
+
+#[test]
+fn fast_distance() {
+    // Within 0.1% for nearby points
+    let pairs = [
+        (GeoLocation::from_coordinates(48.1333, 11.5667), GeoLocation::from_coordinates(48.2, 11.7)),
+        (GeoLocation::from_coordinates(60.1708, 24.9375), GeoLocation::from_coordinates(60.3, 25.2)),
+        (GeoLocation::from_coordinates(-0.05, 179.95), GeoLocation::from_coordinates(0.05, -179.95)),
+    ];
+    for (a, b) in pairs.iter() {
+        let exact = a.distance_to(b);
+        assert!((a.distance_to_fast(b) - exact).abs() < exact * 0.001);
+    }
+    assert_eq!(pairs[0].0.distance_to_fast(&pairs[0].0), 0.0);
+
+    // Far apart it diverges, for antipodal points it's way off
+    let a = GeoLocation::from_coordinates(60.0, -74.0);
+    let b = GeoLocation::from_coordinates(-60.0, 106.0);
+    assert!((a.distance_to(&b) - EARTH_RADIUS * ::std::f64::consts::PI).abs() < 1.0);
+    assert!((a.distance_to_fast(&b) - a.distance_to(&b)).abs() > a.distance_to(&b) * 0.1);
+}
+
+#[test]
+fn distance_to_box() {
+    let b = BoundingBox::from_coordinates(10.0, 20.0, 30.0, 40.0);
+
+    // Inside and on the border
+    assert_eq!(GeoLocation::from_coordinates(15.0, 35.0).distance_to_box(&b), 0.0);
+    assert_eq!(b.top_right().distance_to_box(&b), 0.0);
+
+    // Straight north of the box, to the top edge
+    let north = GeoLocation::from_coordinates(25.0, 35.0);
+    let d = north.distance_to_box(&b);
+    assert!((d - north.distance_to(&GeoLocation::from_coordinates(20.0, 35.0))).abs() < 1e-9);
+    assert!((d - 5.0f64.to_radians() * EARTH_RADIUS).abs() < 1e-6);
+
+    // Diagonally off the bottom-left corner, to the corner
+    let corner = GeoLocation::from_coordinates(5.0, 25.0);
+    assert!((corner.distance_to_box(&b) - corner.distance_to(&b.bottom_left())).abs() < 1e-9);
+    assert!(corner.distance_to_box(&b) > GeoLocation::from_coordinates(5.0, 35.0).distance_to_box(&b));
+
+    assert_eq!(corner.distance_to_box(&b), b.distance_to_point(&corner));
+}
+
 #[test]
 fn sub_distance() {
     let new_york = GeoLocation::from_coordinates(40.7127, -74.0059);
@@ -32,3 +179,118 @@ fn sub_distance() {
     assert_eq!((munich-helsinki).round(), 1590.1646151045206_f64.round());
 }
 
+#[test]
+fn radians() {
+    let l = GeoLocation::from_radians(0.5, 1.0).unwrap();
+    assert!((l.latitude_rad() - 0.5).abs() < 1e-12);
+    assert!((l.longitude_rad() - 1.0).abs() < 1e-12);
+    assert!((l.latitude - 28.64788975654116).abs() < 1e-9);
+
+    assert_eq!(GeoLocation::from_radians(1.6, 0.0).err(), Some(GeohashError::InvalidLatitude(1.6_f64.to_degrees())));
+    assert_eq!(GeoLocation::from_radians(0.0, -3.2).err(), Some(GeohashError::InvalidLongitude((-3.2_f64).to_degrees())));
+    assert!(GeoLocation::from_radians(f64::NAN, 0.0).is_err());
+}
+
+#[test]
+fn angular_distance() {
+    let new_york = GeoLocation::from_coordinates(40.7127, -74.0059);
+    let helsinki = GeoLocation::from_coordinates(60.1708, 24.9375);
+
+    assert!((new_york.angular_distance_to(&helsinki) * EARTH_RADIUS - new_york.distance_to(&helsinki)).abs() < 1e-9);
+    assert_eq!(new_york.angular_distance_to(&new_york), 0.0);
+
+    let north_pole = GeoLocation::from_coordinates(90.0, 0.0);
+    let south_pole = GeoLocation::from_coordinates(-90.0, 0.0);
+    assert!((north_pole.angular_distance_to(&south_pole) - ::std::f64::consts::PI).abs() < 1e-12);
+}
+
+#[test]
+fn destination() {
+    let places = [
+        GeoLocation::from_coordinates(40.7127, -74.0059),
+        GeoLocation::from_coordinates(60.1708, 24.9375),
+        GeoLocation::from_coordinates(48.1333, 11.5667),
+        GeoLocation::from_coordinates(-33.8688, 151.2093),
+        GeoLocation::from_coordinates(-54.8019, -68.3030),
+    ];
+    for a in places.iter() {
+        for b in places.iter() {
+            let d = a.destination(a.bearing_to(b), a.distance_to(b));
+            assert!(d.distance_to(b) < 1e-6);
+        }
+    }
+
+    // Across the antimeridian the longitude stays in range
+    let fiji = GeoLocation::from_coordinates(-17.7, 178.0);
+    let d = fiji.destination(90.0, 500.0);
+    assert!(d.longitude < -175.0 && d.longitude >= -180.0);
+    assert!((fiji.distance_to(&d) - 500.0).abs() < 1e-6);
+
+    // Over the north pole onto the opposite meridian
+    let north = GeoLocation::from_coordinates(89.0, 10.0);
+    let over = north.destination(0.0, GeoLocation::from_coordinates(89.0, 0.0).distance_to(&GeoLocation::from_coordinates(90.0, 0.0)) * 2.0);
+    assert!((over.latitude - 89.0).abs() < 1e-9);
+    assert!((over.longitude + 170.0).abs() < 1e-9);
+    assert!(north.destination(123.0, 0.0).distance_to(&north) < 1e-9);
+}
+
+#[test]
+fn interpolate() {
+    let new_york = GeoLocation::from_coordinates(40.7128, -74.0060);
+    let london = GeoLocation::from_coordinates(51.5074, -0.1278);
+
+    // Endpoints are exact
+    assert!(new_york.interpolate(&london, 0.0) == new_york);
+    assert!(new_york.interpolate(&london, 1.0) == london);
+
+    // Halfway along the equator and along a meridian
+    let origin = GeoLocation::from_coordinates(0.0, 0.0);
+    let p = origin.interpolate(&GeoLocation::from_coordinates(0.0, 90.0), 0.5);
+    assert!(p.latitude.abs() < 1e-9 && (p.longitude - 45.0).abs() < 1e-9);
+    let p = origin.interpolate(&GeoLocation::from_coordinates(90.0, 0.0), 0.5);
+    assert!((p.latitude - 45.0).abs() < 1e-9 && p.longitude.abs() < 1e-9);
+
+    // Fractions of the distance along the great circle
+    let d = new_york.distance_to(&london);
+    for t in [0.1, 0.25, 0.6, 0.9].iter() {
+        let p = new_york.interpolate(&london, *t);
+        assert!((p.distance_to(&new_york) - d * t).abs() < 1e-6);
+        assert!((p.distance_to(&london) - d * (1.0 - t)).abs() < 1e-6);
+        assert!(p.distance_to(&london.interpolate(&new_york, 1.0 - t)) < 1e-6);
+    }
+
+    // Across the antimeridian the shorter way round
+    let a = GeoLocation::from_coordinates(0.0, 170.0);
+    let b = GeoLocation::from_coordinates(0.0, -170.0);
+    assert!(a.interpolate(&b, 0.5).distance_to(&GeoLocation::from_coordinates(0.0, 180.0)) < 1e-6);
+    let p = a.interpolate(&b, 0.75);
+    assert!(p.latitude.abs() < 1e-9 && (p.longitude + 175.0).abs() < 1e-9);
+    // Off the equator the great circle bulges towards the pole, halfway it peaks at
+    // atan(tan(40)/cos(10)), about 40.4325
+    let a = GeoLocation::from_coordinates(40.0, 170.0);
+    let p = a.interpolate(&GeoLocation::from_coordinates(40.0, -170.0), 0.5);
+    assert!((p.longitude.abs() - 180.0).abs() < 1e-9);
+    assert!((p.latitude - 40.4325).abs() < 1e-4);
+}
+
+#[test]
+fn midpoint() {
+    let new_york = GeoLocation::from_coordinates(40.7128, -74.0060);
+    let london = GeoLocation::from_coordinates(51.5074, -0.1278);
+
+    // Out in the North Atlantic, well north of the naive average of the coordinates
+    // at about 46.1N, 37.1W
+    let m = new_york.midpoint(&london);
+    assert!(m.latitude > 50.0 && m.latitude < 55.0);
+    assert!(m.longitude > -45.0 && m.longitude < -35.0);
+    assert!((m.distance_to(&new_york) - m.distance_to(&london)).abs() < 1e-6);
+    assert!((m.distance_to(&new_york) * 2.0 - new_york.distance_to(&london)).abs() < 1e-6);
+    // The usual closed-form midpoint formula gives 52.3684N, 41.2903W
+    assert!((m.latitude - 52.3684).abs() < 1e-4 && (m.longitude + 41.2903).abs() < 1e-4);
+    assert!(m.distance_to(&london.midpoint(&new_york)) < 1e-9);
+
+    assert!(new_york.midpoint(&new_york) == new_york);
+    let north_pole = GeoLocation::from_coordinates(90.0, 0.0);
+    let south_pole = GeoLocation::from_coordinates(-90.0, 0.0);
+    assert!(north_pole.midpoint(&south_pole) == north_pole);
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "rand")]
+
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, encode, decode, fuzz_location};
+
+#[test]
+fn test_fuzz_location() {
+	let p=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	for precision in 1..9 {
+		let cell=decode(&encode(&p, precision));
+		for _ in 0..100 {
+			let fuzzed=fuzz_location(&p, precision);
+			assert!(cell.contains(&fuzzed));
+		}
+	}
+	// The exact position isn't given away
+	assert!((0..100).any(|_| fuzz_location(&p, 5)!=p));
+}
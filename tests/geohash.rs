@@ -1,6 +1,14 @@
 extern crate geohashrust;
 
-use geohashrust::{GeoLocation, BinaryHash, encode, decode};
+use geohashrust::{GeoLocation, BoundingBox, BinaryHash, Direction, GeohashError, CoordinateError, encode, encode_coords, encode_fast, decode, decode_many, neighbor, is_edge_cell,
+                  are_adjacent, encode_upper, encode_with_case, encode_within, is_valid_geohash, geohash_error_meters, try_decode, decode_case_insensitive,
+                  encode_into, geohash_contains, is_within_cell,
+                  neighbors_clockwise, edge_neighbors, neighbors_in_box, cell_is_entirely, precision_for_zoom,
+                  zoom_for_precision, common_prefix, common_prefix_all, geohash_range, neighbors, neighbor_iter, neighbors_within, ring,
+                  cell_dimensions, cells_per_km2, parent, parent_corners, decode_to_location,
+                  decode_with_error, decode_full,
+                  precision_for_cell_size, geohash_to_key, key_to_geohash, cover_bounding_box,
+                  relative_geohash, reconstruct_geohash, geohash_children};
 
 #[test]
 fn test_encode() {
@@ -32,6 +40,148 @@ fn test_encode() {
 	assert_eq!(encode(&l, 12u8), "wtw3r9jjzyjc");
 }
 
+#[test]
+fn test_encode_coords() {
+	assert_eq!(encode_coords(31.55, 121.46, 1).unwrap(), "w");
+	assert_eq!(encode_coords(31.55, 121.46, 9).unwrap(), "wtw77zs2p");
+	assert_eq!(encode_coords(31.16373922, 121.62585927, 7).unwrap(), "wtw3r9j");
+	for p in 1u8..13 {
+		assert_eq!(encode_coords(31.16373922, 121.62585927, p).unwrap(),
+		           encode(&GeoLocation::from_coordinates(31.16373922, 121.62585927), p));
+	}
+	assert_eq!(encode_coords(-90.0, 180.0, 2).unwrap(), "pb");
+	assert_eq!(encode_coords(90.5, 0.0, 5).err(), Some(CoordinateError::InvalidLatitude(90.5)));
+	assert_eq!(encode_coords(0.0, -181.0, 5).err(), Some(CoordinateError::InvalidLongitude(-181.0)));
+}
+
+#[test]
+fn test_encode_fast() {
+	// xorshift, to walk many points reproducibly
+	let mut state = 0x2545f4914f6cdd1du64;
+	let mut next = || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		(state >> 11) as f64 / (1u64 << 53) as f64
+	};
+	for _ in 0..10000 {
+		let l = GeoLocation::from_coordinates(next() * 180.0 - 90.0, next() * 360.0 - 180.0);
+		for p in 1u8..13 {
+			assert_eq!(encode_fast(&l, p), encode(&l, p));
+		}
+	}
+
+	// Cell borders, corners of the world and tiny offsets around the midlines
+	let values = [-180.0, -90.0, -45.0, -1e-300, 0.0, 1e-300, 45.0, 90.0, 180.0, 121.46, 31.55,
+	              360.0 / (1u64 << 30) as f64, 180.0 / (1u64 << 30) as f64 - 90.0];
+	for lat in values.iter().filter(|v| v.abs() <= 90.0) {
+		for lon in values.iter() {
+			let l = GeoLocation::from_coordinates(*lat, *lon);
+			for p in 1u8..13 {
+				assert_eq!(encode_fast(&l, p), encode(&l, p));
+			}
+		}
+	}
+	for h in ["wtw3r9j", "s0000000", "zzzzzzzzzzzz", "00", "9vffb"].iter() {
+		let b = decode(h);
+		for l in [b.bottom_left(), b.top_right(), b.center()].iter() {
+			for p in 1u8..13 {
+				assert_eq!(encode_fast(l, p), encode(l, p));
+			}
+		}
+	}
+
+	// Past 12 characters it's the plain encoding
+	let l = GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	assert_eq!(encode_fast(&l, 15), encode(&l, 15));
+	assert_eq!(encode_fast(&l, 0), "");
+}
+
+#[test]
+fn test_geohash_key_location() {
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	for p in 0u8..13 {
+		assert_eq!(l.geohash_key(p), geohash_to_key(&encode(&l, p), 5*p));
+	}
+	assert_eq!(l.geohash_key(12) >> 25, l.geohash_key(7));
+
+	// Nearby points get closer keys than a distant one
+	let near=GeoLocation::from_coordinates(31.1638, 121.6259);
+	let far=GeoLocation::from_coordinates(48.1333, 11.5667);
+	let key=l.geohash_key(12);
+	assert!(key.abs_diff(near.geohash_key(12)) < key.abs_diff(far.geohash_key(12)));
+
+	// Sorting by key sorts by GeoHash
+	let mut points=[far, l, near, GeoLocation::from_coordinates(-33.8688, 151.2093)];
+	points.sort_by_key(|p| p.geohash_key(9));
+	let hashes: Vec<String>=points.iter().map(|p| encode(p, 9)).collect();
+	let mut sorted=hashes.clone();
+	sorted.sort();
+	assert_eq!(hashes, sorted);
+}
+
+#[test]
+#[should_panic]
+fn test_geohash_key_location_too_long() {
+	GeoLocation::from_coordinates(31.16373922, 121.62585927).geohash_key(13);
+}
+
+#[test]
+fn test_encode_into() {
+	let mut buffer=String::from("a buffer that already holds some text");
+	for l in [GeoLocation::from_coordinates(31.16373922, 121.62585927),
+	          GeoLocation::from_coordinates(-33.8688, 151.2093),
+	          GeoLocation::from_coordinates(90.0, 180.0)].iter() {
+		for precision in [12u8, 7, 1, 0, 20].iter() {
+			encode_into(l, *precision, &mut buffer);
+			assert_eq!(buffer, encode(l, *precision));
+		}
+	}
+	// The allocation is reused
+	let capacity=buffer.capacity();
+	encode_into(&GeoLocation::new(), 8, &mut buffer);
+	assert_eq!(buffer.capacity(), capacity);
+	assert_eq!(buffer, "7zzzzzzz");
+}
+
+#[test]
+fn test_encode_upper() {
+	for &(lat, lon) in [(31.16373922, 121.62585927), (-33.8688, 151.2093), (90.0, 180.0), (-90.0, -180.0)].iter() {
+		let l=GeoLocation::from_coordinates(lat, lon);
+		for precision in 0..13 {
+			let lower=encode(&l, precision);
+			let upper=encode_upper(&l, precision);
+			assert_eq!(upper, lower.to_uppercase());
+			assert_eq!(encode_with_case(&l, precision, true), upper);
+			assert_eq!(encode_with_case(&l, precision, false), lower);
+			if precision > 0 {
+				assert!(decode(&upper)==decode(&lower));
+			}
+		}
+	}
+}
+
+#[test]
+fn test_encode_within() {
+	for l in [GeoLocation::from_coordinates(31.16373922, 121.62585927),
+	          GeoLocation::from_coordinates(0.0, 0.0),
+	          GeoLocation::from_coordinates(-75.5, -179.9)].iter() {
+		for max_error in [10000000.0, 50000.0, 1000.0, 200.0, 5.0, 0.5].iter() {
+			let hash=encode_within(l, *max_error);
+			let b=decode(&hash);
+			assert!(b.contains(l));
+			assert!(b.bottom_left().distance_to(&b.top_right())*1000.0 <= *max_error);
+			// One character less would be too coarse
+			if hash.len()>1 {
+				let p=decode(&hash[..hash.len()-1]);
+				assert!(p.bottom_left().distance_to(&p.top_right())*1000.0 > *max_error);
+			}
+		}
+	}
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	assert_eq!(encode_within(&l, 0.001), encode(&l, 12));
+}
+
 #[test]
 fn test_binary_hash() {
     assert_eq!(BinaryHash::from_string("111001100111100").to_string(), "111001100111100");
@@ -66,6 +216,24 @@ fn test_binary_hash() {
     assert!(!BinaryHash::decode_string("111001100111100").contains(&GeoLocation::from_coordinates(51.0, 121.6)));
 }
 
+#[test]
+fn test_is_valid_geohash() {
+	assert!(is_valid_geohash("wtw3r9jjzyjc"));
+	assert!(is_valid_geohash("0123456789bcdefghjkmnpqrstuvwxyz"));
+	assert!(is_valid_geohash("s"));
+	// Mixed case is fine as long as every letter is in the alphabet
+	assert!(is_valid_geohash("WtW3R9j"));
+	assert!(is_valid_geohash("0123456789BCDEFGHJKMNPQRSTUVWXYZ"));
+	assert!(!is_valid_geohash(""));
+	for c in ["a", "i", "l", "o", "A", "I", "L", "O"].iter() {
+		assert!(!is_valid_geohash(&format!("wtw3{}", c)));
+	}
+	assert!(!is_valid_geohash("wtw3 "));
+	assert!(!is_valid_geohash("wtw3-"));
+	assert!(!is_valid_geohash("wtw3é"));
+	assert!(!is_valid_geohash("wtw3{"));
+}
+
 #[test]
 fn test_decode() {
 	let p=GeoLocation::from_coordinates(31.55, 121.46);
@@ -87,18 +255,841 @@ fn test_decode() {
 	assert!(!decode("wtw3r9jjzyjc").contains(&GeoLocation::from_coordinates(31.16373922, 121.63585927)));
 }
 
+#[test]
+fn test_geohash_contains() {
+	let points=[
+		GeoLocation::from_coordinates(31.163728, 121.625841),
+		GeoLocation::from_coordinates(21.0, 113.0),
+		GeoLocation::from_coordinates(31.6, 121.6),
+		GeoLocation::from_coordinates(-33.8688, 151.2093),
+		GeoLocation::from_coordinates(90.0, 180.0),
+		GeoLocation::from_coordinates(-90.0, -180.0),
+	];
+	let hashes=["", "w", "wtw3r9jjz", "wtw3r9j", "wtw3s", "wt", "r3gx2", "zzzz", "0000", "WTW3R"];
+	for p in points.iter() {
+		for h in hashes.iter() {
+			assert_eq!(geohash_contains(h, p), decode(h).contains(p));
+		}
+	}
+	// Borders belong to the cells on both sides
+	let b=decode("wtw3s");
+	assert!(geohash_contains("wtw3s", &b.top_right()));
+	assert!(geohash_contains("wtw3u", &b.top_right()));
+	assert!(geohash_contains("wtw3s", &b.bottom_left()));
+	assert!(!geohash_contains("wtw3u", &b.bottom_left()));
+}
 
+#[test]
+fn test_is_within_cell() {
+	// Prefixes
+	assert!(is_within_cell("wtw3r9j", "wtw3"));
+	assert!(is_within_cell("wtw3r9j", "w"));
+	assert!(is_within_cell("wtw3r9j", ""));
+	assert!(is_within_cell("", ""));
+	assert!(is_within_cell("WTW3R9J", "wtw3"));
+	// Not prefixes
+	assert!(!is_within_cell("wtw3r9j", "wtw6"));
+	assert!(!is_within_cell("wtw3", "wtw3r9j"));
+	assert!(!is_within_cell("wtw3r9j", "x"));
+	// Same length
+	assert!(is_within_cell("wtw3r", "wtw3r"));
+	assert!(!is_within_cell("wtw3r", "wtw3s"));
+	// Invalid GeoHashes
+	assert!(!is_within_cell("wtw3a", "wtw3"));
+	assert!(!is_within_cell("wtw3r", "wta"));
+	assert!(!is_within_cell("wtw3é", "wtw3"));
+	// Consistent with the cells
+	for h in ["wtw3r9j", "wtw6", "wtw3"].iter() {
+		assert_eq!(is_within_cell(h, "wtw3"), decode("wtw3").contains_box(&decode(h)));
+	}
+}
 
+#[test]
+#[should_panic]
+fn test_geohash_contains_invalid() {
+	// Invalid characters panic even after the point fell outside the cell
+	geohash_contains("0000a", &GeoLocation::from_coordinates(31.6, 121.6));
+}
 
+#[test]
+fn test_decode_full() {
+	let (b, precision) = decode_full("wtw3r9jjz");
+	assert_eq!(precision, 9);
+	assert!(b == decode("wtw3r9jjz"));
+	assert!(b.contains(&GeoLocation::from_coordinates(31.163728, 121.625841)));
+	assert!(decode("wtw3r9jj").contains_box(&b));
 
+	for h in ["w", "wtw3", "wtw3r9jjzzzz"].iter() {
+		let (b, precision) = decode_full(h);
+		assert_eq!(precision as usize, h.len());
+		assert!(b == decode(h));
+	}
+	let (b, precision) = decode_full("");
+	assert_eq!(precision, 0);
+	assert!(b == BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0));
+}
 
+#[test]
+#[should_panic]
+fn test_decode_full_invalid() {
+	decode_full("wtw3a");
+}
 
+#[test]
+fn test_try_decode_case_insensitive() {
+	let b=decode("wtw3r9j");
+	assert!(try_decode("wtw3r9j").unwrap()==b);
+	assert!(try_decode("WTW3R9J").unwrap()==b);
+	assert!(try_decode("wTw3R9j").unwrap()==b);
+	assert!(try_decode("0123456789BCDEFGHJKMNPQRSTUVWXYZ").unwrap()==decode("0123456789bcdefghjkmnpqrstuvwxyz"));
+	for c in ['a', 'i', 'l', 'o', 'A', 'I', 'L', 'O'].iter() {
+		assert_eq!(try_decode(&format!("wtw3{}", c)).err(), Some(GeohashError::InvalidCharacter(*c)));
+	}
+	assert_eq!(try_decode("").err(), Some(GeohashError::EmptyHash));
+}
 
+#[test]
+fn test_decode_case_insensitive() {
+	let b=decode("wtw3r9j");
+	for hash in ["wtw3r9j", "WTW3R9J", "wTw3R9j", "WtW3r9J"].iter() {
+		assert!(decode_case_insensitive(hash).unwrap()==b);
+	}
+	assert_eq!(decode_case_insensitive("wTw3O").err(), Some(GeohashError::InvalidCharacter('O')));
+	assert_eq!(decode_case_insensitive("").err(), Some(GeohashError::EmptyHash));
+}
 
+#[test]
+fn test_decode_to_location() {
+	for hash in ["w", "wt", "wtw3s", "WTW3S", "9q8yy", "u4pruydqqvj", "zzzzzzzzzzzz", "000000000000"].iter() {
+		assert!(decode_to_location(hash)==decode(hash).center());
+	}
+	let l=decode_to_location("s");
+	assert_eq!(l.latitude, 22.5);
+	assert_eq!(l.longitude, 22.5);
+}
 
+#[test]
+fn test_decode_with_error() {
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	for precision in 1..13 {
+		let hash=encode(&l, precision);
+		let (center, error)=decode_with_error(&hash);
+		let b=decode(&hash);
+		assert!(center==b.center());
+		assert_eq!(error.latitude, b.latitude_error());
+		assert_eq!(error.longitude, b.longitude_error());
+		assert!((center.latitude-l.latitude).abs() <= error.latitude);
+		assert!((center.longitude-l.longitude).abs() <= error.longitude);
+	}
+}
+
+#[test]
+fn test_edge_cell() {
+	assert!(is_edge_cell("wtw3z", Direction::N));
+	assert!(is_edge_cell("wtw3z", Direction::E));
+	assert!(!is_edge_cell("wtw3z", Direction::S));
+	assert!(!is_edge_cell("wtw3z", Direction::W));
+	assert!(!is_edge_cell("wtw3s", Direction::N));
+	assert!(!is_edge_cell("wtw3s", Direction::S));
+	assert!(!is_edge_cell("", Direction::N));
+
+	// An edge cell is exactly one whose neighbor doesn't share the parent
+	let dirs=[Direction::N, Direction::NE, Direction::E, Direction::SE,
+	          Direction::S, Direction::SW, Direction::W, Direction::NW];
+	for parent in ["wtw3", "wtw3r"].iter() {
+		for c in "0123456789bcdefghjkmnpqrstuvwxyz".chars() {
+			let hash=format!("{}{}", parent, c);
+			for dir in dirs.iter() {
+				let n=neighbor(&hash, dir.offsets());
+				assert_eq!(is_edge_cell(&hash, *dir), !n.starts_with(*parent), "{} {:?}", hash, dir);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_cell_is_entirely() {
+	let b=decode("wtw3s");
+	let c=b.center();
+	// Clearly north of the point
+	let south=GeoLocation::from_coordinates(b.min_lat-0.1, c.longitude);
+	assert!(cell_is_entirely("wtw3s", &south, Direction::N));
+	assert!(!cell_is_entirely("wtw3s", &south, Direction::S));
+	assert!(!cell_is_entirely("wtw3s", &south, Direction::E));
+	assert!(!cell_is_entirely("wtw3s", &south, Direction::NE));
+	// The facing edge may run through the point
+	assert!(cell_is_entirely("wtw3s", &b.bottom_left(), Direction::NE));
+	assert!(cell_is_entirely("wtw3s", &b.top_left(), Direction::E));
+	assert!(cell_is_entirely("wtw3s", &b.top_right(), Direction::SW));
 
+	// A cell straddling the point is in no direction
+	for dir in [Direction::N, Direction::NE, Direction::E, Direction::SE,
+	            Direction::S, Direction::SW, Direction::W, Direction::NW].iter() {
+		assert!(!cell_is_entirely("wtw3s", &c, *dir));
+	}
+}
 
+#[test]
+fn test_decode_many() {
+	let hashes="wtw3s\nwtw3r9j\nwtw3l\n\nw\nWTW3S\nwtw3#".lines();
+	let results: Vec<_>=decode_many(hashes).collect();
+	assert_eq!(results.len(), 7);
+	assert!(results[0].as_ref().unwrap()==&decode("wtw3s"));
+	assert!(results[1].as_ref().unwrap()==&decode("wtw3r9j"));
+	assert_eq!(results[2].err(), Some(GeohashError::InvalidCharacter('l')));
+	assert_eq!(results[3].err(), Some(GeohashError::EmptyHash));
+	assert!(results[4].as_ref().unwrap()==&decode("w"));
+	assert!(results[5].as_ref().unwrap()==&decode("wtw3s"));
+	assert_eq!(results[6].err(), Some(GeohashError::InvalidCharacter('#')));
 
+	// Stays lazy, the input isn't read past the first item here
+	let input=(0..).map(|i| if i==0 { "wtw3s" } else { panic!("read past the first GeoHash") });
+	let mut it=decode_many(input);
+	assert!(it.next().unwrap().unwrap()==decode("wtw3s"));
+}
 
+#[test]
+fn test_precision_for_cell_size() {
+	// Precision 7 cells are about 153m across at the equator, precision 8 cells 38m
+	assert_eq!(precision_for_cell_size(160.0, 0.0), 7);
+	// Cells never exceed the size asked for: 100m takes precision 8, as precision 7
+	// cells are too big, not the 7 that a cheat-sheet rounding to the closest size gives
+	assert_eq!(precision_for_cell_size(100.0, 0.0), 8);
+	assert_eq!(precision_for_cell_size(40.0, 0.0), 8);
+	// Cells shrink east-west away from the equator, precision 5 is 4.9km x 4.9km at the equator
+	assert_eq!(precision_for_cell_size(4000.0, 0.0), 6);
+	assert_eq!(precision_for_cell_size(5000.0, 0.0), 5);
+	assert_eq!(precision_for_cell_size(700.0, 0.0), 7);
+	assert_eq!(precision_for_cell_size(700.0, 60.0), 6);
+	// 1km takes precision 7, not 6, where precision 6 cells are 1.2km wide, and 6 where
+	// they shrink under 1km
+	assert_eq!(precision_for_cell_size(1000.0, 0.0), 7);
+	assert_eq!(precision_for_cell_size(1000.0, 40.0), 6);
+	// Precision 9 cells are 4.8m x 4.8m at the equator
+	assert_eq!(precision_for_cell_size(5.0, 0.0), 9);
+	assert_eq!(precision_for_cell_size(4.5, 0.0), 10);
+	// The table in the docs
+	for (precision, height, width) in [(1, 5004e3, 5004e3), (2, 625e3, 1251e3), (3, 156e3, 156e3), (4, 19.5e3, 39.1e3),
+	                                   (5, 4.89e3, 4.89e3), (6, 611.0, 1222.0), (7, 153.0, 153.0), (8, 19.1, 38.2),
+	                                   (9, 4.77, 4.77), (10, 0.596, 1.19), (11, 0.149, 0.149), (12, 0.0186, 0.0373)].iter() {
+		let (h, w)=cell_dimensions(*precision, 0.0);
+		assert!((h-height).abs() < height*0.005 && (w-width).abs() < width*0.005);
+	}
+	// Requests smaller than the finest cells are capped
+	assert_eq!(precision_for_cell_size(0.001, 0.0), 12);
+	assert_eq!(precision_for_cell_size(1e9, 0.0), 1);
+}
+
+#[test]
+fn test_zoom_levels() {
+	let precision=precision_for_zoom(10);
+	assert!((1..10).contains(&precision));
+	for precision in 1..13 {
+		assert_eq!(precision_for_zoom(zoom_for_precision(precision)), precision);
+	}
+	// Deeper zoom levels never ask for coarser cells
+	let precisions: Vec<u8>=(0..31).map(precision_for_zoom).collect();
+	assert!(precisions.windows(2).all(|w| w[0] <= w[1]));
+	assert_eq!(precision_for_zoom(255), 12);
+}
+
+#[test]
+fn test_cells_per_km2() {
+	for &latitude in [0.0, 31.0, -45.0, 70.0].iter() {
+		for precision in 1..10 {
+			let (height, width)=cell_dimensions(precision, latitude);
+			assert!((cells_per_km2(precision, latitude)*height*width/1.0e6-1.0).abs() < 1e-9);
+			// Each character splits a cell in 32
+			let ratio=cells_per_km2(precision+1, latitude)/cells_per_km2(precision, latitude);
+			assert!((ratio-32.0).abs() < 1e-9);
+		}
+	}
+	assert!(cells_per_km2(7, 60.0) > 1.9*cells_per_km2(7, 0.0));
+
+	// Precision 7 cells at the equator are 152.7m square, not the ~170 per km² expected
+	// in the request, which would take 77m cells
+	let (height, width)=cell_dimensions(7, 0.0);
+	assert!((height-152.7).abs() < 0.1 && (width-152.7).abs() < 0.1);
+	assert!((cells_per_km2(7, 0.0)-42.88).abs() < 0.01);
+}
+
+#[test]
+fn test_geohash_error_meters() {
+	// Twice the error is the size of the cell
+	let (ns, ew)=geohash_error_meters("wtw3r9j");
+	let b=decode("wtw3r9j");
+	let c=b.center();
+	assert!((2.0*ns-b.bottom_left().distance_to(&b.top_left())*1000.0).abs() < 0.01);
+	assert!((2.0*ew-GeoLocation::from_coordinates(c.latitude, b.min_lon)
+		.distance_to(&GeoLocation::from_coordinates(c.latitude, b.max_lon))*1000.0).abs() < 0.01);
+
+	// East-west error shrinks away from the equator
+	let (ns_eq, ew_eq)=geohash_error_meters("s0000");
+	let (ns_north, ew_north)=geohash_error_meters("upbpb");
+	assert!((ns_eq-ns_north).abs() < 1e-6);
+	assert!(ew_north < ew_eq/10.0);
+	assert!(geohash_error_meters("s0000000") < geohash_error_meters("s000000"));
+}
 
+#[test]
+fn test_encoder() {
+	let l=GeoLocation::from_coordinates(-33.8688, 151.2093);
+	assert_eq!(l.encoder().string(), encode(&l, 12));
+	for precision in 0..13 {
+		let encoder=l.encoder().precision(precision);
+		let hash=encoder.string();
+		assert_eq!(hash, encode(&l, precision));
+		assert!(encoder.binary()==BinaryHash::encode(&l, precision*5));
+		assert_eq!(encoder.binary().len(), precision*5);
+		let (h, b)=encoder.with_box();
+		assert_eq!(h, hash);
+		assert!(b.contains(&l));
+	}
+}
+
+#[test]
+fn test_binary_hash_long() {
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let bh=BinaryHash::encode(&l, 80);
+	assert_eq!(bh.len(), 80);
+	assert_eq!(bh.to_string().len(), 80);
+	assert!(bh.to_string().starts_with(&BinaryHash::encode(&l, 60).to_string()));
+	assert!(bh==BinaryHash::from_string(&bh.to_string()));
+	let b=bh.decode();
+	assert!(b.contains(&l));
+	// 16 more bits than fit into a u64, 8 of them along the latitude
+	assert_eq!(b.latitude_range()*256.0, BinaryHash::encode(&l, 64).decode().latitude_range());
+
+	let long=BinaryHash::encode(&l, 255);
+	assert!(long.decode().contains(&l));
+	let mut from_geohash=BinaryHash::from_geohash(&encode(&l, 12));
+	from_geohash.push(true);
+	assert_eq!(from_geohash.len(), 61);
+	assert_eq!(from_geohash.to_string(), BinaryHash::from_geohash(&encode(&l, 12)).to_string()+"1");
+}
+
+#[test]
+fn test_binary_hash_iter() {
+	assert_eq!(BinaryHash::new().iter().next(), None);
+	assert_eq!(BinaryHash::new().iter().size_hint(), (0, Some(0)));
+
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let bh=BinaryHash::encode(&l, 100);
+	let mut it=bh.iter();
+	assert_eq!(it.size_hint(), (100, Some(100)));
+	it.next();
+	assert_eq!(it.len(), 99);
+	let bits: String=bh.iter().map(|b| if b {'1'} else {'0'}).collect();
+	assert_eq!(bits, bh.to_string());
+	for (n, b) in bh.iter().enumerate() {
+		assert_eq!(b, bh.test(n as u8));
+	}
+	let mut count=0;
+	for _ in &bh {
+		count+=1;
+	}
+	assert_eq!(count, 100);
+
+	// First differing bit between neighboring cells
+	let a=BinaryHash::from_geohash("wtw3s");
+	let b=BinaryHash::from_geohash("wtw3t");
+	assert_eq!(a.iter().zip(b.iter()).position(|(x, y)| x != y), Some(24));
+}
+
+#[test]
+fn test_binary_hash_to_geohash() {
+	for l in [GeoLocation::from_coordinates(31.16373922, 121.62585927),
+	          GeoLocation::from_coordinates(-33.8688, 151.2093),
+	          GeoLocation::from_coordinates(90.0, 180.0)].iter() {
+		for precision in 0..13 {
+			let hash=encode(l, precision);
+			assert_eq!(BinaryHash::encode(l, precision*5).to_geohash(), hash);
+			assert_eq!(BinaryHash::from_geohash(&hash).to_geohash(), hash);
+		}
+	}
+	// Bit-level changes show up in the GeoHash
+	let mut bh=BinaryHash::from_geohash("wtw3s");
+	bh.pop();
+	bh.push(true);
+	assert_eq!(bh.to_geohash(), "wtw3t");
+	assert_eq!(BinaryHash::from_geohash("WTW3S").to_geohash(), "wtw3s");
+	assert_eq!(BinaryHash::encode(&GeoLocation::from_coordinates(0.0, 0.0), 80).to_geohash().len(), 16);
+}
+
+#[test]
+#[should_panic]
+fn test_binary_hash_to_geohash_partial() {
+	BinaryHash::from_string("1110011").to_geohash();
+}
+
+#[test]
+fn test_binary_hash_pop_truncate() {
+	let mut bh=BinaryHash::new();
+	assert_eq!(bh.pop(), None);
+	for c in "1110011001".chars() {
+		bh.push(c=='1');
+	}
+	assert_eq!(bh.pop(), Some(true));
+	assert_eq!(bh.pop(), Some(false));
+	assert_eq!(bh.len(), 8);
+	assert_eq!(bh.to_string(), "11100110");
+	// Bits pushed after popping replace the popped ones
+	bh.push(true);
+	assert!(bh==BinaryHash::from_string("111001101"));
+	bh.truncate(12);
+	assert_eq!(bh.to_string(), "111001101");
+	bh.truncate(5);
+	assert_eq!(bh.len(), 5);
+	assert!(bh==BinaryHash::from_geohash("w"));
+	bh.truncate(0);
+	assert!(bh.is_empty());
+	assert!(bh==BinaryHash::new());
+
+	// Across the 64-bit word boundaries
+	let l=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	for precision in [129u8, 128, 100, 65, 64, 63, 1].iter() {
+		let mut bh=BinaryHash::encode(&l, 130);
+		bh.truncate(*precision);
+		assert!(bh==BinaryHash::encode(&l, *precision));
+		assert_eq!(bh.to_string().len(), *precision as usize);
+	}
+	let mut bh=BinaryHash::encode(&l, 66);
+	let s=bh.to_string();
+	assert_eq!(bh.pop(), Some(s.ends_with('1')));
+	assert_eq!(bh.pop(), Some(&s[64..65]=="1"));
+	assert_eq!(bh.to_string(), s[..64]);
+	assert!(bh==BinaryHash::encode(&l, 64));
+
+	// Still Copy, a copy doesn't follow later pushes
+	let copy=bh;
+	bh.push(true);
+	assert_eq!(copy.len(), 64);
+	assert!(copy==BinaryHash::encode(&l, 64));
+}
 
+#[test]
+#[should_panic]
+fn test_binary_hash_too_long() {
+	let mut bh=BinaryHash::encode(&GeoLocation::from_coordinates(0.0, 0.0), 255);
+	bh.push(true);
+}
+
+#[test]
+fn test_binary_hash_try_push() {
+	let mut bh=BinaryHash::encode(&GeoLocation::from_coordinates(0.0, 0.0), 64);
+	// The 65th bit goes into a second word
+	assert!(bh.try_push(true).is_ok());
+	assert_eq!(bh.len(), 65);
+	assert!(bh.test(64));
+	assert_eq!(bh.to_string(), BinaryHash::encode(&GeoLocation::from_coordinates(0.0, 0.0), 64).to_string()+"1");
+
+	while bh.len() < 254 {
+		assert!(bh.try_push(false).is_ok());
+	}
+	// The 255th bit still fits, the 256th doesn't
+	assert_eq!(bh.try_push(true), Ok(()));
+	assert_eq!(bh.len(), 255);
+	assert!(bh.test(254));
+	let full=bh;
+	assert_eq!(bh.try_push(true), Err(GeohashError::PrecisionOverflow));
+	assert_eq!(bh.len(), 255);
+	assert_eq!(GeohashError::PrecisionOverflow.to_string(), "BinaryHash can't hold more than 255 bits");
+	assert!(bh==full);
+}
+
+#[test]
+#[should_panic]
+fn test_encoder_binary_too_long() {
+	GeoLocation::from_coordinates(0.0, 0.0).encoder().precision(52).binary();
+}
+
+#[test]
+fn test_binary_hash_conversions() {
+	let bh=BinaryHash::from_geohash("wtw3r9jjzyjc");
+	assert_eq!(bh.len(), 60);
+	let b: BoundingBox=bh.into();
+	assert!(b==decode("wtw3r9jjzyjc"));
+	let b: BoundingBox=(&bh).into();
+	assert!(b==bh.decode());
+
+	let bh: BinaryHash="wtw77".into();
+	assert!(bh==BinaryHash::encode(&GeoLocation::from_coordinates(31.55, 121.46), 25));
+	assert!(BoundingBox::from(bh)==decode("wtw77"));
+	assert!(BinaryHash::from("").empty());
+}
+
+#[test]
+fn test_neighbor_across_pole() {
+	// Top row of the world, the cell across the north pole has the same latitude
+	// range and a longitude shifted by 180 degrees
+	for hash in ["zzz", "bpb", "upbp", "gzzzzzzzz"].iter() {
+		let across=neighbor(hash, (1, 0));
+		let b=decode(hash);
+		let a=decode(&across);
+		assert!(a.min_lat==b.min_lat && a.max_lat==b.max_lat, "{} {}", hash, across);
+		let shift=(a.center().longitude-b.center().longitude+360.0)%360.0;
+		assert!((shift-180.0).abs() < 1e-9, "{} {}", hash, across);
+		// And back again
+		assert_eq!(neighbor(&across, (1, 0)), *hash);
+	}
+	assert_eq!(neighbor("zzz", (1, 0)), "gzz");
+	// Keeps going south on the other side
+	assert_eq!(neighbor("zzz", (2, 0)), neighbor("gzz", (-1, 0)));
+	// Diagonal steps move along the row first
+	assert_eq!(neighbor("zzz", (1, -1)), neighbor("zzy", (1, 0)));
+
+	// South pole
+	assert_eq!(neighbor("000", (-1, 0)), "h00");
+	assert_eq!(neighbor("h00", (-1, 0)), "000");
+}
+
+#[test]
+fn test_neighbor_wraps_antimeridian() {
+	assert_eq!(neighbor("zzz", (0, 1)), "bpb");
+	assert_eq!(neighbor("bpb", (0, -1)), "zzz");
+	assert_eq!(neighbor("8", (0, -1)), "x");
+}
+
+#[test]
+fn test_edge_neighbors() {
+	for hash in ["wtw3s", "u4pruydqqvj", "zzz", "b", "00", "9q8yy"].iter() {
+		let ns=neighbors(hash);
+		let edges=edge_neighbors(hash);
+		assert_eq!(edges, [ns[7].clone(), ns[2].clone(), ns[5].clone(), ns[4].clone()]);
+		for e in edges.iter() {
+			assert_eq!(e.len(), hash.len());
+		}
+	}
+	assert_eq!(edge_neighbors("wtw3s"), ["wtw3u", "wtw3k", "wtw3t", "wtw3e"]);
+}
+
+#[test]
+fn test_neighbor_iter() {
+	for hash in ["wtw3s", "zzz", "0", "u4pruydqqvj"].iter() {
+		let it=neighbor_iter(hash);
+		assert_eq!(it.size_hint(), (8, Some(8)));
+		let ns: Vec<String>=it.collect();
+		assert_eq!(ns, neighbors(hash)[1..].to_vec());
+		// Same as stepping to each neighbor on its own, across the poles too
+		let offsets=[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+		let stepped: Vec<String>=offsets.iter().map(|o| neighbor(hash, *o)).collect();
+		assert_eq!(ns, stepped);
+	}
+	let mut it=neighbor_iter("wtw3s");
+	for remaining in (0..8).rev() {
+		assert!(it.next().is_some());
+		assert_eq!(it.size_hint(), (remaining, Some(remaining)));
+	}
+	assert!(it.next().is_none());
+	assert_eq!(neighbor_iter("wtw3s").filter(|n| n.starts_with("wtw3")).count(), 8);
+}
+
+#[test]
+fn test_neighbors_within() {
+	assert_eq!(neighbors_within("wtw3s", 0), vec!["wtw3s"]);
+	let mut block=neighbors_within("wtw3s", 1);
+	assert_eq!(block.len(), 9);
+	assert_eq!(block[4], "wtw3s");
+	let mut ns=neighbors("wtw3s");
+	block.sort();
+	ns.sort();
+	assert_eq!(block, ns);
+
+	// Rows go east, starting from the bottom-left corner
+	let block=neighbors_within("wtw3s", 3);
+	assert_eq!(block.len(), 49);
+	assert_eq!(block[0], ring("wtw3s", 3)[0]);
+	for row in 0..7 {
+		for col in 0..6 {
+			assert_eq!(neighbor(&block[row*7+col], (0, 1)), block[row*7+col+1]);
+		}
+	}
+	let mut rings: Vec<String>=(0..4).flat_map(|k| ring("wtw3s", k)).collect();
+	let mut sorted=block.clone();
+	rings.sort();
+	sorted.sort();
+	assert_eq!(sorted, rings);
+
+	// Cells reached twice near the pole are listed once
+	let polar=neighbors_within("zzz", 2);
+	let mut unique=polar.clone();
+	unique.sort();
+	unique.dedup();
+	assert_eq!(unique.len(), polar.len());
+	assert!(polar.len() < 25);
+	assert_eq!(neighbors_within("s", 4).len(), 32);
+	// A block far wider than the world still only holds its cells
+	assert_eq!(neighbors_within("s", 40).len(), 32);
+}
+
+#[test]
+fn test_neighbors_clockwise() {
+	for hash in ["wtw3s", "9q8yy", "u4pruydqqvj", "6gkzwgjzn820"].iter() {
+		let ns=neighbors_clockwise(hash);
+		let center=decode(hash).center();
+		// Bearings of the cell centers increase clockwise from the north
+		let bearings: Vec<f64>=ns.iter().map(|n| center.bearing_to(&decode(n).center())).collect();
+		assert!(bearings[0] < 1e-6 || bearings[0] > 360.0-1e-6);
+		assert!((bearings[2]-90.0).abs() < 1.0);
+		assert!((bearings[4]-180.0).abs() < 1e-6);
+		assert!((bearings[6]-270.0).abs() < 1.0);
+		assert!(bearings[1..].windows(2).all(|w| w[0] < w[1]));
+		assert_eq!(ns[0], neighbor(hash, Direction::N.offsets()));
+		assert_eq!(ns[2], neighbor(hash, Direction::E.offsets()));
+	}
+}
+
+#[test]
+fn test_neighbors_in_box() {
+	// A box whose bottom-left corner lies inside the cell only reaches north and east
+	let b=decode("wtw3s");
+	let c=b.center();
+	let bbox=BoundingBox::from_coordinates(c.latitude, c.latitude+0.5, c.longitude, c.longitude+0.5);
+	let inward=neighbors_in_box("wtw3s", &bbox);
+	assert_eq!(inward, vec![neighbor("wtw3s", (0, 1)), neighbor("wtw3s", (1, 0)), neighbor("wtw3s", (1, 1))]);
+
+	// Along the western edge of a box, the western neighbors are left out
+	let bbox=BoundingBox::from_coordinates(c.latitude-0.5, c.latitude+0.5, c.longitude, c.longitude+0.5);
+	let inward=neighbors_in_box("wtw3s", &bbox);
+	assert_eq!(inward.len(), 5);
+	assert!(inward.iter().all(|n| decode(n).min_lon >= b.min_lon));
+
+	assert_eq!(neighbors_in_box("wtw3s", &BoundingBox::world()).len(), 8);
+	assert!(neighbors_in_box("wtw3s", &decode("9q8yy")).is_empty());
+}
+
+#[test]
+fn test_are_adjacent() {
+	assert!(are_adjacent("wtw3s", "wtw37"));
+	assert!(are_adjacent("wtw37", "wtw3s"));
+	assert!(are_adjacent("WTW3S", "wtw3k"));
+	// Across the antimeridian
+	assert!(are_adjacent("zzz", "bpb"));
+	assert!(!are_adjacent("wtw3s", "9q8yy"));
+	assert!(!are_adjacent("wtw3s", "wtw3s"));
+	assert!(!are_adjacent("wtw3s", "wtw3"));
+	assert!(!are_adjacent("wtw3s", "wtw3a"));
+	assert!(!are_adjacent("", ""));
+}
+
+#[test]
+fn test_neighbor_matches_cell_geometry() {
+	// The neighbor's cell is one cell away from the original in each direction
+	for hash in ["wtw3s", "wtw3sjj", "wtw3r9jjzyjc", "9q8yy", "u4pruydqqvj", "6gkzwgjzn820"].iter() {
+		let b=decode(hash);
+		for dlat in -1i8..2 {
+			for dlon in -1i8..2 {
+				let n=decode(&neighbor(hash, (dlat, dlon)));
+				let c=n.center();
+				assert!((c.latitude-b.center().latitude-b.latitude_range()*dlat as f64).abs() < 1e-9);
+				assert!((c.longitude-b.center().longitude-b.longitude_range()*dlon as f64).abs() < 1e-9);
+			}
+		}
+	}
+	assert_eq!(neighbor("WTW3S", (-1, -1)), "wtw37");
+	assert_eq!(neighbor("", (1, 1)), "");
+}
+
+#[test]
+fn test_neighbor_is_exact() {
+	// Neighbors share their edges exactly, down to the last bit, at full precision
+	for i in 0..200 {
+		let l=GeoLocation::from_coordinates(-80.0+0.8*i as f64+1e-7, -179.0+1.79*i as f64-1e-7);
+		for precision in [1u8, 5, 11, 12].iter() {
+			let hash=encode(&l, *precision);
+			let b=decode(&hash);
+			let north=decode(&neighbor(&hash, (1, 0)));
+			let east=decode(&neighbor(&hash, (0, 1)));
+			let south=decode(&neighbor(&hash, (-1, 0)));
+			let west=decode(&neighbor(&hash, (0, -1)));
+			if b.max_lat<90.0 {
+				assert!(north.min_lat==b.max_lat && north.min_lon==b.min_lon);
+			}
+			if b.min_lat>-90.0 {
+				assert!(south.max_lat==b.min_lat && south.max_lon==b.max_lon);
+			}
+			if b.max_lon<180.0 {
+				assert!(east.min_lon==b.max_lon && east.min_lat==b.min_lat);
+			}
+			if b.min_lon>-180.0 {
+				assert!(west.max_lon==b.min_lon && west.max_lat==b.max_lat);
+			}
+			if b.max_lat<90.0 {
+				assert_eq!(neighbor(&neighbor(&hash, (1, 1)), (-1, -1)), hash);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_geohash_key() {
+	let mut hashes=cover_bounding_box(&geohashrust::BoundingBox::from_coordinates(-60.0, 60.0, -170.0, 170.0), 2);
+	hashes.extend(cover_bounding_box(&decode("wtw3"), 6).into_iter().map(|h| h[..2].to_string()));
+	let mut by_key=hashes.clone();
+	by_key.sort_by_key(|h| geohash_to_key(h, 40));
+	hashes.sort();
+	assert_eq!(by_key, hashes);
+
+	let mut hashes=cover_bounding_box(&decode("wtw3"), 7);
+	hashes.reverse();
+	let mut by_key=hashes.clone();
+	by_key.sort_by_key(|h| geohash_to_key(h, 64));
+	hashes.sort();
+	assert_eq!(by_key, hashes);
+
+	// Descendants fall into the key range of their prefix
+	let lo=geohash_to_key("wtw3", 64);
+	let hi=lo+(1u64 << (64-20));
+	for h in cover_bounding_box(&decode("wtw3"), 6).iter() {
+		let k=geohash_to_key(h, 64);
+		assert!(k>=lo && k<hi);
+	}
+
+	for h in ["w", "wtw3r", "wtw3r9jjzyjc", ""].iter() {
+		let k=geohash_to_key(h, 60);
+		assert_eq!(key_to_geohash(k, 60, h.len() as u8), *h);
+	}
+	assert_eq!(geohash_to_key("", 0), 0);
+	assert_eq!(key_to_geohash(geohash_to_key("wtw3r", 25), 25, 5), "wtw3r");
+}
+
+#[test]
+fn test_relative_geohash() {
+	let reference=encode(&GeoLocation::from_coordinates(31.16, 121.62), 10);
+	let mut total=0;
+	for i in 0..50 {
+		let p=GeoLocation::from_coordinates(31.16+(i as f64)*0.00037, 121.62-(i as f64)*0.00041);
+		let delta=relative_geohash(&p, &reference);
+		assert!(delta.len() < reference.len());
+		total+=delta.len();
+		assert_eq!(reconstruct_geohash(&reference, &delta), encode(&p, 10));
+	}
+	// Dense points share most of the reference
+	assert!(total < 50*6);
+
+	let far=GeoLocation::from_coordinates(-33.87, 151.21);
+	assert_eq!(relative_geohash(&far, &reference), encode(&far, 10));
+	assert_eq!(reconstruct_geohash(&reference, &encode(&far, 10)), encode(&far, 10));
+}
+
+#[test]
+fn test_common_prefix() {
+	assert_eq!(common_prefix("wtw3r9j", "wtw3r9j"), "wtw3r9j");
+	assert_eq!(common_prefix("wtw3r", "wtw3e"), "wtw3");
+	assert_eq!(common_prefix("wtw3r9j", "wtw3"), "wtw3");
+	assert_eq!(common_prefix("wtw3", "wtw3r9j"), "wtw3");
+	assert_eq!(common_prefix("wtw3r", "xtw3r"), "");
+	assert_eq!(common_prefix("WTW3R", "wtw3e"), "wtw3");
+	assert_eq!(common_prefix("", "wtw3"), "");
+	// The prefix is the smallest cell containing both
+	let p=common_prefix("wtw3r", "wtw3e");
+	assert!(decode(&p).contains_box(&decode("wtw3r")) && decode(&p).contains_box(&decode("wtw3e")));
+}
+
+#[test]
+#[should_panic]
+fn test_common_prefix_invalid() {
+	common_prefix("wtw3r", "wtw3a");
+}
+
+#[test]
+fn test_common_prefix_all() {
+	let hashes=vec!["wtw3r9j".to_string(), "wtw3s".to_string(), "wtw37e".to_string()];
+	assert_eq!(common_prefix_all(&hashes), "wtw3");
+	assert_eq!(common_prefix_all(&hashes[..1]), "wtw3r9j");
+	assert_eq!(common_prefix_all(&[]), "");
+	assert_eq!(common_prefix_all(&["wtw3".to_string(), "9q8yy".to_string()]), "");
+	assert_eq!(common_prefix_all(&["wtw3".to_string(), "wtw3r9j".to_string()]), "wtw3");
+	assert_eq!(common_prefix_all(&["WTW3S".to_string(), "wtw3s".to_string()]), "wtw3s");
+	assert_eq!(common_prefix_all(&["wtw3".to_string(), "".to_string()]), "");
+}
+
+#[test]
+#[should_panic]
+fn test_common_prefix_all_invalid() {
+	common_prefix_all(&["wtw3".to_string(), "wtwa".to_string()]);
+}
+
+#[test]
+fn test_geohash_range() {
+	let (lo, hi)=geohash_range("wtw3", 6);
+	let inside=|h: &str| lo.as_str() <= h && h <= hi.as_str();
+	// Every descendant at the target length is in the range
+	for h in cover_bounding_box(&decode("wtw3"), 6).iter() {
+		assert!(inside(h));
+	}
+	// Cells around it aren't
+	for n in neighbors("wtw3").iter().skip(1) {
+		for h in cover_bounding_box(&decode(n), 6).iter() {
+			assert!(!inside(h));
+		}
+	}
+	assert_eq!(geohash_range("WTW3", 4), ("wtw3".to_string(), "wtw3".to_string()));
+	assert_eq!(geohash_range("", 2), ("00".to_string(), "zz".to_string()));
+}
+
+#[test]
+#[should_panic]
+fn test_geohash_range_too_short() {
+	geohash_range("wtw3", 3);
+}
+
+#[test]
+fn test_parent_corners() {
+	for hash in ["wtw3s", "9q8yy", "u4pruydqqvj", "w"].iter() {
+		let p=parent(hash).unwrap();
+		assert_eq!(p.len(), hash.len()-1);
+		assert!(hash.starts_with(&p));
+
+		// The parent's box holds all corners of the child
+		let parent_box=decode(&p);
+		for corner in decode(hash).corners().iter() {
+			assert!(parent_box.contains(corner));
+		}
+		assert!(parent_corners(hash)==parent_box.corners());
+	}
+	assert!(parent_corners("w")==BoundingBox::world().corners());
+}
+
+#[test]
+#[should_panic]
+fn test_parent_corners_empty() {
+	parent_corners("");
+}
+
+#[test]
+fn test_geohash_children() {
+	for hash in ["wtw3", "wtw3s", "", "9q8yy"].iter() {
+		let children=geohash_children(hash);
+		assert_eq!(children.len(), 32);
+		let parent_box=decode(hash);
+		let mut area=0.0;
+		for (i, child) in children.iter().enumerate() {
+			assert!(child.starts_with(hash));
+			assert_eq!(child.len(), hash.len()+1);
+			assert_eq!(parent(child).unwrap(), *hash);
+			let b=decode(child);
+			assert!(parent_box.contains_box(&b));
+			area+=b.latitude_range()*b.longitude_range();
+			// No two children overlap
+			for other in children.iter().skip(i+1) {
+				let o=decode(other);
+				assert!(b.max_lat <= o.min_lat || o.max_lat <= b.min_lat || b.max_lon <= o.min_lon || o.max_lon <= b.min_lon);
+			}
+		}
+		assert!((area-parent_box.latitude_range()*parent_box.longitude_range()).abs() < 1e-9*area);
+
+		// The same cells as a grid of 4x8 or 8x4, depending on the bits left
+		let (rows, cols)=if hash.len()%2==0 { (4, 8) } else { (8, 4) };
+		let grid=parent_box.subdivide(rows, cols);
+		for b in children.iter().map(|h| decode(h)) {
+			assert!(grid.contains(&b));
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn test_geohash_children_invalid() {
+	geohash_children("wtwa");
+}
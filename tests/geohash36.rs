@@ -0,0 +1,59 @@
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, BoundingBox, encode36, decode36};
+
+#[test]
+fn test_encode36() {
+	let l=GeoLocation::from_coordinates(51.504444, -0.086666);
+	assert_eq!(encode36(&l, 10), "bdrdC26BqH");
+	assert_eq!(encode36(&l, 3), "bdr");
+	assert_eq!(encode36(&l, 0), "");
+
+	// Corners of the world, north is the first row
+	assert_eq!(encode36(&GeoLocation::from_coordinates(90.0, -180.0), 3), "222");
+	assert_eq!(encode36(&GeoLocation::from_coordinates(90.0, 180.0), 3), "777");
+	assert_eq!(encode36(&GeoLocation::from_coordinates(-90.0, -180.0), 3), "RRR");
+	assert_eq!(encode36(&GeoLocation::from_coordinates(-90.0, 180.0), 3), "XXX");
+}
+
+#[test]
+fn test_decode36() {
+	assert!(decode36("")==BoundingBox::from_coordinates(-90.0, 90.0, -180.0, 180.0));
+	assert!(decode36("2")==BoundingBox::from_coordinates(60.0, 90.0, -180.0, -120.0));
+	assert!(decode36("X")==BoundingBox::from_coordinates(-90.0, -60.0, 120.0, 180.0));
+	// Case matters
+	assert!(decode36("b")==BoundingBox::from_coordinates(30.0, 60.0, -60.0, 0.0));
+	assert!(decode36("B")==BoundingBox::from_coordinates(30.0, 60.0, 0.0, 60.0));
+}
+
+#[test]
+fn test_geohash36_round_trip() {
+	let places=[
+		GeoLocation::from_coordinates(51.504444, -0.086666),
+		GeoLocation::from_coordinates(31.16373922, 121.62585927),
+		GeoLocation::from_coordinates(-33.8688, 151.2093),
+		GeoLocation::from_coordinates(40.7127, -74.0059),
+		GeoLocation::from_coordinates(-54.8019, -68.3030),
+		GeoLocation::from_coordinates(0.0, 0.0),
+	];
+	for l in places.iter() {
+		for precision in 1u8..12 {
+			let hash=encode36(l, precision);
+			assert_eq!(hash.len(), precision as usize);
+			let b=decode36(&hash);
+			assert!(b.contains(l));
+			// Each character shrinks the cell 6 times on both axes
+			assert!((b.latitude_range()-180.0/6f64.powi(precision as i32)).abs() < 1e-9);
+			assert!((b.longitude_range()-360.0/6f64.powi(precision as i32)).abs() < 1e-9);
+			// The center of a cell encodes back to the cell
+			assert_eq!(encode36(&b.center(), precision), hash);
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn test_decode36_invalid() {
+	// Vowels and 0/1 aren't in the alphabet
+	decode36("bdra");
+}
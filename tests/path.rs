@@ -0,0 +1,139 @@
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, are_collinear, simplify_path, closest_point_on_segment, path_length,
+                  path_length_rhumb, point_at_distance};
+
+#[test]
+fn bearing_and_cross_track() {
+    let a = GeoLocation::from_coordinates(0.0, 0.0);
+    assert!((a.bearing_to(&GeoLocation::from_coordinates(0.0, -10.0)) - 270.0).abs() < 1e-9);
+    assert!((a.bearing_to(&GeoLocation::from_coordinates(-10.0, 0.0)) - 180.0).abs() < 1e-9);
+
+    // Off a meridian, to the east is right of a northbound path
+    let start = GeoLocation::from_coordinates(10.0, 20.0);
+    let end = GeoLocation::from_coordinates(30.0, 20.0);
+    let east = GeoLocation::from_coordinates(20.0, 21.0);
+    let west = GeoLocation::from_coordinates(20.0, 19.0);
+    assert!(east.cross_track_distance(&start, &end) > 0.0);
+    assert!(west.cross_track_distance(&start, &end) < 0.0);
+    assert!((east.cross_track_distance(&start, &end) + west.cross_track_distance(&start, &end)).abs() < 1e-9);
+    assert!(GeoLocation::from_coordinates(25.0, 20.0).cross_track_distance(&start, &end).abs() < 1e-9);
+}
+
+#[test]
+fn collinear() {
+    let a = GeoLocation::from_coordinates(10.0, 20.0);
+    let b = GeoLocation::from_coordinates(20.0, 20.0);
+    let c = GeoLocation::from_coordinates(30.0, 20.0);
+    assert!(are_collinear(&a, &b, &c, 0.001));
+    // Beyond the end points is still on the same great circle
+    assert!(are_collinear(&a, &GeoLocation::from_coordinates(50.0, 20.0), &c, 0.001));
+
+    // About 1km off the meridian
+    let offset = GeoLocation::from_coordinates(20.0, 20.01);
+    assert!(!are_collinear(&a, &offset, &c, 100.0));
+    assert!(are_collinear(&a, &offset, &c, 1100.0));
+
+    // Degenerate great circle
+    assert!(are_collinear(&a, &a, &a, 0.0));
+    assert!(!are_collinear(&a, &b, &a, 1000.0));
+}
+
+#[test]
+fn simplify() {
+    // A slightly noisy run along the equator collapses to its end points
+    let run: Vec<GeoLocation> = (0..50)
+        .map(|i| GeoLocation::from_coordinates(if i % 2 == 0 {0.00001} else {-0.00001}, i as f64 * 0.01))
+        .collect();
+    let simplified = simplify_path(&run, 5.0);
+    assert_eq!(simplified.len(), 2);
+    assert!(simplified[0] == run[0]);
+    assert!(simplified[1] == run[49]);
+    // Nothing is dropped with a tolerance tighter than the noise
+    assert_eq!(simplify_path(&run, 0.1).len(), 50);
+
+    // A sharp corner is kept
+    let mut corner: Vec<GeoLocation> = (0..10)
+        .map(|i| GeoLocation::from_coordinates(0.0, i as f64 * 0.01))
+        .collect();
+    corner.extend((1..10).map(|i| GeoLocation::from_coordinates(i as f64 * 0.01, 0.09)));
+    let simplified = simplify_path(&corner, 5.0);
+    assert_eq!(simplified.len(), 3);
+    assert!(simplified[0] == corner[0]);
+    assert!(simplified[1] == GeoLocation::from_coordinates(0.0, 0.09));
+    assert!(simplified[2] == corner[18]);
+
+    assert!(simplify_path(&[], 1.0).is_empty());
+    assert_eq!(simplify_path(&corner[..2], 1e6).len(), 2);
+}
+
+#[test]
+fn closest_point() {
+    let start = GeoLocation::from_coordinates(40.0, -74.0);
+    let end = GeoLocation::from_coordinates(52.0, 0.0);
+    let mid = start.destination(start.bearing_to(&end), start.distance_to(&end) / 2.0);
+
+    // A point perpendicular to the middle of the segment snaps to it
+    let bearing = (mid.bearing_to(&end) + 90.0) % 360.0;
+    let p = mid.destination(bearing, 200.0);
+    let snapped = closest_point_on_segment(&p, &start, &end);
+    assert!(snapped.distance_to(&mid) < 0.001);
+    assert!((p.along_track_distance(&start, &end) - start.distance_to(&end) / 2.0).abs() < 0.001);
+
+    // Points beyond either end are clamped
+    let before = start.destination(start.bearing_to(&end) + 180.0, 100.0);
+    assert!(closest_point_on_segment(&before, &start, &end) == start);
+    let after = end.destination(end.bearing_to(&start) + 180.0, 100.0);
+    assert!(closest_point_on_segment(&after, &start, &end) == end);
+
+    // Degenerate segment
+    assert!(closest_point_on_segment(&p, &start, &start) == start);
+}
+
+#[test]
+fn path_lengths() {
+    let a = GeoLocation::from_coordinates(31.23, 121.47);
+    let b = GeoLocation::from_coordinates(39.90, 116.40);
+    let c = GeoLocation::from_coordinates(22.54, 114.06);
+    let total = path_length(&[a, b, c]);
+    assert!((total - (a.distance_to(&b) + b.distance_to(&c))).abs() < 1e-9);
+    assert_eq!(path_length(&[]), 0.0);
+    assert_eq!(path_length(&[a]), 0.0);
+
+    let rhumb = path_length_rhumb(&[a, b, c]);
+    assert!((rhumb - (a.rhumb_distance_to(&b) + b.rhumb_distance_to(&c))).abs() < 1e-9);
+    assert!(rhumb >= total);
+    assert_eq!(path_length_rhumb(&[a]), 0.0);
+
+    // Along a meridian both are the same
+    let north = GeoLocation::from_coordinates(40.0, 121.47);
+    assert!((path_length_rhumb(&[a, north]) - path_length(&[a, north])).abs() < 1e-6);
+    // Across the antimeridian the short way is taken
+    let west = GeoLocation::from_coordinates(0.0, 179.0);
+    let east = GeoLocation::from_coordinates(0.0, -179.0);
+    assert!((west.rhumb_distance_to(&east) - west.distance_to(&east)).abs() < 1e-6);
+}
+
+#[test]
+fn point_at_distances() {
+    let a = GeoLocation::from_coordinates(31.23, 121.47);
+    let b = GeoLocation::from_coordinates(39.90, 116.40);
+    let c = GeoLocation::from_coordinates(22.54, 114.06);
+    let path = [a, b, c];
+    let total = path_length(&path) * 1000.0;
+
+    assert!(point_at_distance(&path, 0.0).unwrap() == a);
+    assert!(point_at_distance(&path, total).unwrap() == c);
+    assert!(point_at_distance(&path, total + 1.0).is_none());
+    assert!(point_at_distance(&path, -1.0).is_none());
+    assert!(point_at_distance(&[], 0.0).is_none());
+    assert!(point_at_distance(&[a], 0.0).unwrap() == a);
+
+    // The end of the first leg, and a point half way along the second
+    let first = a.distance_to(&b) * 1000.0;
+    assert!(point_at_distance(&path, first).unwrap().distance_to(&b) < 1e-9);
+    let second = b.distance_to(&c) * 1000.0;
+    let p = point_at_distance(&path, first + second / 2.0).unwrap();
+    assert!((p.distance_to(&b) - p.distance_to(&c)).abs() < 1e-6);
+    assert!(p.cross_track_distance(&b, &c).abs() < 1e-6);
+}
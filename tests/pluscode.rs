@@ -0,0 +1,51 @@
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, GeohashError, encode, decode, geohash_to_plus_code, plus_code_to_geohash,
+                  decode_plus_code};
+
+#[test]
+fn test_plus_code_round_trip() {
+	let zurich=GeoLocation::from_coordinates(47.365590, 8.524997);
+	let hash=encode(&zurich, 9);
+	let code=geohash_to_plus_code(&hash, 10);
+	assert_eq!(code, "8FVC9G8F+6X");
+	assert!(decode(&hash).contains(&zurich));
+	assert!(decode_plus_code(&code).unwrap().contains(&zurich));
+
+	// Going back lands on a GeoHash containing the point at a coarser precision
+	let back=plus_code_to_geohash(&code, 7).unwrap();
+	assert_eq!(back, &hash[..7]);
+	assert!(decode(&back).contains(&zurich));
+}
+
+#[test]
+fn test_plus_code_lengths() {
+	assert_eq!(geohash_to_plus_code("u0qj3yx", 2), "8F000000+");
+	assert_eq!(geohash_to_plus_code("u0qj3yx", 8), "8FVC9G8F+");
+	let b=decode_plus_code("8FVC0000+").unwrap();
+	assert!((b.latitude_range()-1.0).abs() < 1e-9 && (b.longitude_range()-1.0).abs() < 1e-9);
+	assert!((b.min_lat-47.0).abs() < 1e-9 && (b.min_lon-8.0).abs() < 1e-9);
+
+	// Corners of the world
+	assert_eq!(geohash_to_plus_code("zzzzzz", 10), "CVXXXXWV+WR");
+	assert_eq!(geohash_to_plus_code("000000", 10), "22222234+35");
+}
+
+#[test]
+fn test_plus_code_errors() {
+	assert_eq!(decode_plus_code("").err(), Some(GeohashError::EmptyHash));
+	assert_eq!(decode_plus_code("8FVC9G8F").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("9G8F+6X").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("8FVC9G8F+6").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("8F00VC00+").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("8F000000+22").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("XFVC9G8F+6X").err(), Some(GeohashError::MalformedData));
+	assert_eq!(decode_plus_code("8FVC9G8A+6X").err(), Some(GeohashError::InvalidCharacter('A')));
+	assert!(decode_plus_code("8fvc9g8f+6x").unwrap()==decode_plus_code("8FVC9G8F+6X").unwrap());
+}
+
+#[test]
+#[should_panic]
+fn test_plus_code_odd_length() {
+	geohash_to_plus_code("u0qj3yx", 5);
+}
@@ -0,0 +1,200 @@
+extern crate geohashrust;
+
+use std::collections::HashSet;
+
+use geohashrust::{GeoLocation, BoundingBox, encode, neighbors, ring, spiral, nearest_cell_excluding, unique_geohash,
+                  geohash_distance_to_point, precision_histogram,
+                  smallest_enclosing_circle, min_distinguishing_precision, flood_fill,
+                  cover_bounding_box, decode};
+
+#[test]
+fn test_ring() {
+	assert_eq!(ring("wtw3s", 0), vec!["wtw3s"]);
+	for k in 1..4 {
+		let r=ring("wtw3s", k);
+		assert_eq!(r.len(), 8*k as usize);
+		let unique: HashSet<&String>=r.iter().collect();
+		assert_eq!(unique.len(), r.len());
+	}
+
+	let mut s: Vec<String>=spiral("wtw3s").take(25).collect();
+	let mut expected=ring("wtw3s", 0);
+	expected.extend(ring("wtw3s", 1));
+	expected.extend(ring("wtw3s", 2));
+	assert_eq!(s, expected);
+	let mut ns=neighbors("wtw3s");
+	s.truncate(9);
+	s.sort();
+	ns.sort();
+	assert_eq!(s, ns);
+}
+
+#[test]
+fn test_nearest_cell_excluding() {
+	let p=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let center=encode(&p, 6);
+	let mut exclude=HashSet::new();
+	assert_eq!(nearest_cell_excluding(&p, 6, &exclude), Some(center.clone()));
+
+	// The center is excluded, one of its neighbors takes over
+	exclude.insert(center.clone());
+	let h=nearest_cell_excluding(&p, 6, &exclude).unwrap();
+	assert!(h!=center);
+	assert!(neighbors(&center).contains(&h));
+
+	// With the whole first ring excluded too, the second ring is used
+	exclude.extend(ring(&center, 1));
+	let h=nearest_cell_excluding(&p, 6, &exclude).unwrap();
+	assert!(ring(&center, 2).contains(&h));
+
+	// Nothing left within the search limit
+	for k in 2..17 {
+		exclude.extend(ring(&center, k));
+	}
+	assert_eq!(nearest_cell_excluding(&p, 6, &exclude), None);
+}
+
+#[test]
+fn test_unique_geohash() {
+	// Two points about 100m apart
+	let a=GeoLocation::from_coordinates(31.16373922, 121.62585927);
+	let b=GeoLocation::from_coordinates(31.16463922, 121.62585927);
+	let lone=GeoLocation::from_coordinates(-33.8688, 151.2093);
+	let points=[a, b, lone];
+
+	let ha=unique_geohash(&a, &[b, lone], 12);
+	let hb=unique_geohash(&b, &[a, lone], 12);
+	assert!(ha!=hb);
+	assert!(!hb.starts_with(&ha) && !ha.starts_with(&hb));
+	assert!(ha.len()>=6 && ha.len()<=8);
+	assert_eq!(ha, encode(&a, ha.len() as u8));
+	assert_eq!(hb, encode(&b, hb.len() as u8));
+	// Minimal, one character less is shared
+	assert_eq!(encode(&b, ha.len() as u8 - 1), ha[..ha.len()-1]);
+
+	// Nobody else in the southern hemisphere
+	assert_eq!(unique_geohash(&lone, &points[..2], 12).len(), 1);
+	// A point on its own still gets a valid GeoHash
+	let h=unique_geohash(&lone, &[], 12);
+	assert_eq!(h, encode(&lone, 1));
+	assert!(decode(&h).contains(&lone));
+
+	// Coincident points can't be isolated
+	assert_eq!(unique_geohash(&a, &[a], 9), encode(&a, 9));
+}
+
+#[test]
+fn test_min_distinguishing_precision() {
+	let points=[
+		GeoLocation::from_coordinates(31.16373922, 121.62585927),
+		GeoLocation::from_coordinates(-33.87, 151.21),
+		GeoLocation::from_coordinates(48.86, 2.35),
+	];
+	assert_eq!(min_distinguishing_precision(&points, 12), Some(1));
+	assert_eq!(min_distinguishing_precision(&points, 0), None);
+
+	// Every point gets its own cell at the returned precision, but not one shorter
+	let near=[points[0], GeoLocation::from_coordinates(31.16, 121.63), points[1]];
+	let p=min_distinguishing_precision(&near, 12).unwrap();
+	let distinct=|precision| near.iter().map(|l| encode(l, precision)).collect::<HashSet<_>>().len()==near.len();
+	assert!(distinct(p));
+	assert!(!distinct(p-1));
+
+	// Coincident points are never told apart
+	assert_eq!(min_distinguishing_precision(&[points[0], points[1], points[0]], 12), None);
+	assert_eq!(min_distinguishing_precision(&[points[0]], 12), Some(0));
+	assert_eq!(min_distinguishing_precision(&[], 12), Some(0));
+}
+
+#[test]
+fn test_geohash_distance_to_point() {
+	let b=decode("wtw3r");
+	// Inside
+	assert_eq!(geohash_distance_to_point("wtw3r", &b.center()), 0.0);
+	assert_eq!(geohash_distance_to_point("wtw3r", &b.top_left()), 0.0);
+	// About 111m north of the northern edge
+	let north=GeoLocation::from_coordinates(b.max_lat+0.001, b.center().longitude);
+	let d=geohash_distance_to_point("wtw3r", &north);
+	assert!((d-0.1112).abs() < 0.001);
+	// Far away
+	let sydney=GeoLocation::from_coordinates(-33.8688, 151.2093);
+	let d=geohash_distance_to_point("wtw3r", &sydney);
+	assert!(d > 7000.0 && d < sydney.distance_to(&b.center()));
+}
+
+#[test]
+fn test_precision_histogram() {
+	// Most points clustered in one cell, a few scattered
+	let mut clustered: Vec<GeoLocation>=(0..90)
+		.map(|i| GeoLocation::from_coordinates(31.16+(i as f64)*0.0001, 121.62))
+		.collect();
+	clustered.extend((0..10).map(|i| GeoLocation::from_coordinates(-40.0+(i as f64)*8.0, -100.0+(i as f64)*20.0)));
+	let h=precision_histogram(&clustered, 4);
+	assert_eq!(h.values().sum::<usize>(), 100);
+	assert_eq!(*h.values().max().unwrap(), 90);
+	assert_eq!(h.len(), 11);
+
+	// One point in the middle of each cell of a grid
+	let uniform: Vec<GeoLocation>=geohashrust::cover_bounding_box(&decode("wtw"), 5).iter()
+		.map(|c| decode(c).center())
+		.collect();
+	let h=precision_histogram(&uniform, 4);
+	assert_eq!(h.len(), 32);
+	assert!(h.values().all(|&n| n==32));
+}
+
+#[test]
+fn test_smallest_enclosing_circle() {
+	assert!(smallest_enclosing_circle(&[]).is_none());
+	let a=GeoLocation::from_coordinates(31.0, 121.0);
+	let (c, r)=smallest_enclosing_circle(&[a]).unwrap();
+	assert!(c==a && r==0.0);
+
+	// The circle passes through the two far apart points and contains the third
+	let b=GeoLocation::from_coordinates(31.0, 121.1);
+	let inner=GeoLocation::from_coordinates(31.01, 121.05);
+	let (c, r)=smallest_enclosing_circle(&[a, inner, b]).unwrap();
+	let half=a.distance_to(&b)*500.0;
+	assert!((r-half).abs() < 1.0);
+	assert!((c.distance_to(&a)*1000.0-r).abs() < 1.0);
+	assert!((c.distance_to(&b)*1000.0-r).abs() < 1.0);
+	assert!(c.distance_to(&inner)*1000.0 < r);
+	let bbox=BoundingBox::from_center_radius(&c, r);
+	assert!(bbox.contains(&a) && bbox.contains(&b) && bbox.contains(&inner));
+
+	// An acute triangle needs all three points on the circle
+	let top=GeoLocation::from_coordinates(31.08, 121.05);
+	let (c, r)=smallest_enclosing_circle(&[a, b, top, inner]).unwrap();
+	for p in [a, b, top].iter() {
+		assert!((c.distance_to(p)*1000.0-r).abs() < 20.0);
+	}
+	assert!(c.distance_to(&inner)*1000.0 < r);
+
+	// Every point is enclosed for a larger set
+	let points: Vec<GeoLocation>=(0..200)
+		.map(|i| GeoLocation::from_coordinates(31.0+((i*37)%101) as f64*0.001, 121.0+((i*53)%97) as f64*0.001))
+		.collect();
+	let (c, r)=smallest_enclosing_circle(&points).unwrap();
+	assert!(points.iter().all(|p| c.distance_to(p)*1000.0 <= r+1e-6));
+}
+
+#[test]
+fn test_flood_fill() {
+	// A rectangular region of 3x2 cells
+	let region=BoundingBox::merged(&decode("wtw37"), &decode("wtw3t"));
+	let cells=cover_bounding_box(&region, 5);
+	assert_eq!(cells.len(), 6);
+	let filled=flood_fill("wtw3s", 100, |h| cells.iter().any(|c| c==h));
+	assert_eq!(filled.len(), 6);
+	assert_eq!(filled[0], "wtw3s");
+	assert!(cells.iter().all(|c| filled.contains(c)));
+
+	// Capped at the maximum
+	assert_eq!(flood_fill("wtw3s", 4, |h| cells.iter().any(|c| c==h)).len(), 4);
+	assert!(flood_fill("wtw3s", 0, |_| true).is_empty());
+	// The start has to pass too
+	assert!(flood_fill("9q8yy", 100, |h| cells.iter().any(|c| c==h)).is_empty());
+	// Cells only touching at a corner are connected
+	let diagonal=["wtw3s".to_string(), "wtw3v".to_string()];
+	assert_eq!(flood_fill("wtw3s", 100, |h| diagonal.iter().any(|c| c==h)).len(), 2);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "serde")]
+
+extern crate geohashrust;
+extern crate serde_json;
+
+use geohashrust::{GeoLocation, BoundingBox, decode};
+
+#[test]
+fn geolocation_round_trip() {
+    let l = GeoLocation::from_coordinates(31.16373922, 121.62585927);
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, r#"{"latitude":31.16373922,"longitude":121.62585927}"#);
+    assert!(serde_json::from_str::<GeoLocation>(&json).unwrap() == l);
+
+    let l: GeoLocation = serde_json::from_str(r#"{"longitude": -180.0, "latitude": 90.0}"#).unwrap();
+    assert!(l == GeoLocation::from_coordinates(90.0, -180.0));
+}
+
+#[test]
+fn geolocation_invalid() {
+    let e = serde_json::from_str::<GeoLocation>(r#"{"latitude":91.0,"longitude":0.0}"#).err().unwrap();
+    assert!(e.to_string().starts_with("Latitude 91 out of range"));
+    let e = serde_json::from_str::<GeoLocation>(r#"{"latitude":0.0,"longitude":-181.5}"#).err().unwrap();
+    assert!(e.to_string().starts_with("Longitude -181.5 out of range"));
+    assert!(serde_json::from_str::<GeoLocation>(r#"{"latitude":0.0}"#).is_err());
+    assert!(serde_json::from_str::<GeoLocation>(r#"[0.0, 0.0, 0.0]"#).is_err());
+}
+
+#[test]
+fn boundingbox_round_trip() {
+    let b = decode("wtw3s");
+    let json = serde_json::to_string(&b).unwrap();
+    assert!(json.starts_with(r#"{"min_lat":"#));
+    assert!(serde_json::from_str::<BoundingBox>(&json).unwrap() == b);
+    let world = serde_json::to_string(&BoundingBox::world()).unwrap();
+    assert_eq!(world, r#"{"min_lat":-90.0,"max_lat":90.0,"min_lon":-180.0,"max_lon":180.0}"#);
+    assert!(serde_json::from_str::<BoundingBox>(&world).unwrap() == BoundingBox::world());
+}
+
+#[test]
+fn boundingbox_invalid() {
+    assert!(serde_json::from_str::<BoundingBox>(r#"{"min_lat":-90.5,"max_lat":0.0,"min_lon":0.0,"max_lon":1.0}"#).is_err());
+    assert!(serde_json::from_str::<BoundingBox>(r#"{"min_lat":0.0,"max_lat":1.0,"min_lon":0.0,"max_lon":190.0}"#).is_err());
+    let e = serde_json::from_str::<BoundingBox>(r#"{"min_lat":10.0,"max_lat":1.0,"min_lon":0.0,"max_lon":1.0}"#).err().unwrap();
+    assert!(e.to_string().starts_with("min_lat 10 is greater than max_lat 1"));
+}
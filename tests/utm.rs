@@ -0,0 +1,58 @@
+extern crate geohashrust;
+
+use geohashrust::{GeoLocation, UtmCoordinate};
+
+#[test]
+fn utm_zone() {
+    let munich = GeoLocation::from_coordinates(48.1333, 11.5667);
+    let utm = munich.to_utm();
+    assert_eq!(utm.zone, 32);
+    assert_eq!(utm.hemisphere, 'N');
+
+    let sydney = GeoLocation::from_coordinates(-33.8688, 151.2093);
+    let utm = sydney.to_utm();
+    assert_eq!(utm.zone, 56);
+    assert_eq!(utm.hemisphere, 'S');
+
+    // Norway and Svalbard exceptions
+    assert_eq!(GeoLocation::from_coordinates(60.39, 5.32).to_utm().zone, 32);
+    assert_eq!(GeoLocation::from_coordinates(78.22, 15.65).to_utm().zone, 33);
+}
+
+#[test]
+fn utm_known_values() {
+    // Central meridian of zone 31 on the equator
+    let utm = GeoLocation::from_coordinates(0.0, 3.0).to_utm();
+    assert!((utm.easting - 500000.0).abs() < 1e-6);
+    assert!(utm.northing.abs() < 1e-6);
+
+    // CN Tower, 17T 630084 4833438
+    let utm = GeoLocation::from_coordinates(43.642567, -79.387139).to_utm();
+    assert_eq!(utm.zone, 17);
+    assert!((utm.easting - 630084.0).abs() < 1.0);
+    assert!((utm.northing - 4833438.0).abs() < 1.0);
+}
+
+#[test]
+fn utm_round_trip() {
+    let points = [
+        GeoLocation::from_coordinates(48.1333, 11.5667),
+        GeoLocation::from_coordinates(40.7127, -74.0059),
+        GeoLocation::from_coordinates(-33.8688, 151.2093),
+        GeoLocation::from_coordinates(31.23, 121.473),
+    ];
+    for p in points.iter() {
+        let l = GeoLocation::from_utm(&p.to_utm());
+        // distance_to is in kilometers, so within a meter
+        assert!(p.distance_to(&l) < 0.001);
+    }
+
+    let utm = UtmCoordinate {
+        zone: 17,
+        hemisphere: 'N',
+        easting: 630084.0,
+        northing: 4833438.0,
+    };
+    let l = GeoLocation::from_utm(&utm);
+    assert!(l.distance_to(&GeoLocation::from_coordinates(43.642567, -79.387139)) < 0.001);
+}